@@ -0,0 +1,140 @@
+use crate::chunk::{ChunkData, Chunk};
+use crate::world::{Block, World};
+
+use core::fmt::{Formatter, Display, Result as FmtResult};
+use core::convert::TryFrom;
+use core::iter::Iterator;
+
+
+
+
+
+/// A biome of a 4D Miner world.
+#[derive(PartialEq, Clone, Debug, Copy, Hash, Eq)]
+#[repr(u8)]
+pub enum Biome {
+	/// Grassland – the most common biome, covered in [`Block::Grass`].
+	Grassland,
+	/// Midnight – a dark biome, covered in [`Block::MidnightGrass`].
+	Midnight,
+	/// Desert – a dry biome, covered in [`Block::Sand`].
+	Desert,
+	/// Snow – a cold biome, covered in [`Block::Snow`].
+	Snow
+}
+
+impl Biome {
+	/// Every biome.
+	pub const ALL:[Self; 4] = [Self::Grassland, Self::Midnight, Self::Desert, Self::Snow];
+	
+	#[inline(always)] pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Grassland => "Grassland",
+			Self::Midnight  => "Midnight",
+			Self::Desert    => "Desert",
+			Self::Snow      => "Snow"
+		}
+	}
+	
+	/// Finds the biome whose ground is covered in the specified block, if there is one.
+	pub const fn from_surface(block:Block) -> Option<Self> {
+		match block {
+			Block::Grass         => Some(Self::Grassland),
+			Block::MidnightGrass => Some(Self::Midnight),
+			Block::Sand          => Some(Self::Desert),
+			Block::Snow          => Some(Self::Snow),
+			_                    => None
+		}
+	}
+	
+	/// Classifies a column of a chunk, at an (X, Z, W) location within the chunk, by its surface.
+	/// 
+	/// Plants and trees are looked through to find the ground, and ground frozen over with [`Block::Ice`] is classed as snow.  
+	/// Returns [`None`] if the ground is not covered in any biome's [surface](Biome::surface) block (such as bare stone),
+	///  or the location is outside of the chunk.
+	pub fn of_column(chunk:&Chunk, (x, z, w):(usize, usize, usize)) -> Option<Self> {
+		for y in (0..World::HEIGHT).rev() {
+			match Block::try_from(chunk.get_block((x, y, z, w))?) {
+				Ok(Block::Ice)                                            => return Some(Self::Snow),
+				Ok(block) if block == Block::Air || block.is_vegetation() => continue,
+				Ok(block)                                                 => return Self::from_surface(block),
+				Err(_)                                                    => return None
+			}
+		}
+		None
+	}
+	
+	/// The block that the ground of this biome is covered in.
+	pub const fn surface(&self) -> Block {
+		match self {
+			Self::Grassland => Block::Grass,
+			Self::Midnight  => Block::MidnightGrass,
+			Self::Desert    => Block::Sand,
+			Self::Snow      => Block::Snow
+		}
+	}
+	
+	/// The block found in the few layers below the [surface](Biome::surface) of this biome.
+	pub const fn subsurface(&self) -> Block {
+		match self {
+			Self::Grassland => Block::Dirt,
+			Self::Midnight  => Block::MidnightSoil,
+			Self::Desert    => Block::Sand,
+			Self::Snow      => Block::Dirt
+		}
+	}
+	
+	/// The block that makes up the bulk of the ground below this biome.
+	pub const fn stone(&self) -> Block {
+		match self {
+			Self::Grassland => Block::Stone,
+			Self::Midnight  => Block::MidnightStone,
+			Self::Desert    => Block::Sandstone,
+			Self::Snow      => Block::Stone
+		}
+	}
+}
+
+impl Display for Biome {
+	fn fmt(&self, f:&mut Formatter) -> FmtResult { f.write_str(self.as_str()) }
+}
+
+
+
+/// The [`Biome`] of every (X, Z, W) column of a chunk.
+#[derive(PartialEq, Clone, Debug, Hash, Eq)]
+#[repr(transparent)]
+pub struct BiomeMap([[[Option<Biome>; Chunk::WETH]; Chunk::LENGTH]; Chunk::WIDTH]);
+
+impl BiomeMap {
+	/// Classifies every column of a chunk with [`Biome::of_column`].
+	pub fn of_chunk(chunk:&Chunk) -> Self {
+		let mut map = [[[None; Chunk::WETH]; Chunk::LENGTH]; Chunk::WIDTH];
+		for (x, plane) in map.iter_mut().enumerate() {
+			for (z, row) in plane.iter_mut().enumerate() {
+				for (w, biome) in row.iter_mut().enumerate() { *biome = Biome::of_column(chunk, (x, z, w)); }
+			}
+		}
+		Self(map)
+	}
+	
+	/// Gets the biome of the column at an (X, Z, W) location within the chunk.
+	/// 
+	/// Returns [`None`] if the column could not be classified, or the location is outside of the chunk.
+	pub fn get(&self, (x, z, w):(usize, usize, usize)) -> Option<Biome> { *self.0.get(x)?.get(z)?.get(w)? }
+	
+	/// Returns an iterator over the biome of every column, alongside its (X, Z, W) location within the chunk.
+	pub fn iter(&self) -> impl Iterator<Item=((usize, usize, usize), Option<Biome>)> + '_ {
+		self.0.iter().enumerate().flat_map(|(x, plane)| plane.iter().enumerate().flat_map(move |(z, row)| {
+			row.iter().enumerate().map(move |(w, &biome)| ((x, z, w), biome))
+		}))
+	}
+	
+	/// The number of columns which belong to a biome.
+	pub fn count(&self, biome:Biome) -> usize { self.iter().filter(|&(_, b)| b == Some(biome)).count() }
+	
+	/// The biome which most columns belong to, if any columns could be classified.
+	pub fn dominant(&self) -> Option<Biome> {
+		Biome::ALL.into_iter().map(|biome| (biome, self.count(biome))).filter(|&(_, n)| n > 0).max_by_key(|&(_, n)| n).map(|(biome, _)| biome)
+	}
+}
\ No newline at end of file
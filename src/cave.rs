@@ -0,0 +1,92 @@
+use crate::flood::{Adjacency, flood};
+use crate::world::{Block, World};
+use crate::schematic::Schematic;
+use crate::chunk::Chunk;
+use crate::util::WorldRect4;
+
+use std::collections::{HashMap, HashSet};
+use core::convert::TryFrom;
+use core::iter::Iterator;
+use std::vec::Vec;
+
+
+
+
+
+/// A cave: a volume of air below the ground, with every block connected to the others face-to-face.
+/// 
+/// Air counts as below the ground when it is below the [ground height](World::ground_height) of its column,
+///  so shafts which are open to the sky are not part of caves, but tunnels into hillsides are.
+#[derive(PartialEq, Clone, Debug, Eq)]
+pub struct Cave {
+	/// The smallest area containing every block of the cave.
+	pub bounds:WorldRect4,
+	/// The location of every block of the cave.
+	pub blocks:Vec<(i64, i64, i64, i64)>,
+	/// Whether the cave is surrounded by solid blocks (or the bottom of the world) on every side;
+	///  caves which open onto the surface, reach the edge of the searched region, or border unloaded chunks are not.
+	pub enclosed:bool,
+	/// The number of blocks of each ID which border the cave.
+	pub exposed:HashMap<u8, usize>
+}
+
+impl Cave {
+	/// The number of blocks in this cave.
+	#[inline(always)] pub fn volume(&self) -> usize { self.blocks.len() }
+	
+	/// The number of blocks of the specified ID which border this cave.
+	#[inline(always)] pub fn exposed_count(&self, block_id:u8) -> usize { self.exposed.get(&block_id).copied().unwrap_or(0) }
+	
+	/// Returns an iterator over every ore which borders this cave, alongside the number of blocks of it that do.
+	pub fn exposed_ores(&self) -> impl Iterator<Item=(Block, usize)> + '_ {
+		self.exposed.iter().filter_map(|(&id, &n)| Block::try_from(id).ok().filter(Block::is_ore).map(|ore| (ore, n)))
+	}
+	
+	/// Creates a mask of this cave, as a [`Schematic`] of its bounds with `block_id` for every block of the cave, and air everywhere else.
+	pub fn mask(&self, block_id:u8) -> Schematic {
+		let blocks = self.blocks.iter().copied().collect::<HashSet<_>>();
+		Schematic::new(self.bounds.size(), self.bounds.iter().map(|loc| if blocks.contains(&loc) { block_id } else { Block::Air as u8 }))
+	}
+	
+	/// Finds every cave within a region of a world, in X, Y, Z, W order of their first block.
+	pub(crate) fn find(world:&World, within:WorldRect4) -> Vec<Self> {
+		let within      = within.normalized();
+		let mut heights = HashMap::new();
+		let mut is_cave = |loc:(i64, i64, i64, i64)| {
+			within.contains(loc) && world.get_block(loc) == Some(Block::Air as u8) &&
+			heights.entry((loc.0, loc.2, loc.3)).or_insert_with(|| world.ground_height((loc.0, loc.2, loc.3))).is_some_and(|h| loc.1 < h)
+		};
+		
+		let mut seen  = HashSet::new();
+		let mut caves = Vec::new();
+		for (key, local) in within.split_chunks() {
+			if world.chunk(key).is_none() { continue; }
+			
+			let base = (key.0*Chunk::WIDTH as i64, 0, key.1*Chunk::LENGTH as i64, key.2*Chunk::WETH as i64);
+			for (x, y, z, w) in local.iter() {
+				let blocks = flood((base.0 + x as i64, y as i64, base.2 + z as i64, base.3 + w as i64), Adjacency::Faces, &mut is_cave, &mut seen);
+				let Some(&first) = blocks.first() else { continue; };
+				
+				let members      = blocks.iter().copied().collect::<HashSet<_>>();
+				let mut bounds   = WorldRect4::new(first, first);
+				let mut enclosed = true;
+				let mut borders  = HashSet::new();
+				for &loc in &blocks {
+					bounds = bounds.union(&WorldRect4::new(loc, loc));
+					for next in Adjacency::Faces.neighbours(loc).filter(|next| !members.contains(next)) {
+						match world.get_block(next) {
+							Some(id) if id != Block::Air as u8 => { borders.insert(next); }
+							None if next.1 < 0                 => (),
+							_                                  => enclosed = false
+						}
+					}
+				}
+				
+				let mut exposed = HashMap::new();
+				for loc in borders { *exposed.entry(world.get_block(loc).unwrap()).or_insert(0) += 1; }
+				caves.push(Self {bounds, blocks, enclosed, exposed});
+			}
+		}
+		caves
+	}
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use crate::inventory::Inventory;
+
+use core::iter::{FromIterator, IntoIterator, Iterator};
+use std::collections::{hash_map, HashMap};
+
+
+
+
+
+/// The contents of every chest in a world, keyed by the location of its [`Block::Chest`](crate::world::Block::Chest) in world-space.
+/// 
+/// Chests only hold their contents in memory; they are not read from, or written to, a world's folder.
+#[derive(PartialEq, Default, Clone, Debug, Eq)]
+pub struct Chests(HashMap<(i64, i64, i64, i64), Inventory>);
+
+impl Chests {
+	/// Creates a new, empty, [`Chests`].
+	#[inline(always)] pub fn new() -> Self { Self(HashMap::new()) }
+	
+	/// The number of chests.
+	#[inline(always)] pub fn len(&self) -> usize { self.0.len() }
+	
+	/// Whether there are no chests.
+	#[inline(always)] pub fn is_empty(&self) -> bool { self.0.is_empty() }
+	
+	/// Gets the contents of the chest at a location in world-space, if there is one.
+	#[inline(always)] pub fn get(&self, loc:(i64, i64, i64, i64)) -> Option<&Inventory> { self.0.get(&loc) }
+	
+	/// Mutably gets the contents of the chest at a location in world-space, if there is one.
+	#[inline(always)] pub fn get_mut(&mut self, loc:(i64, i64, i64, i64)) -> Option<&mut Inventory> { self.0.get_mut(&loc) }
+	
+	/// Sets the contents of the chest at a location in world-space, returning its previous contents (if any).
+	#[inline(always)] pub fn insert(&mut self, loc:(i64, i64, i64, i64), contents:Inventory) -> Option<Inventory> { self.0.insert(loc, contents) }
+	
+	/// Removes the chest at a location in world-space, returning its contents (if there was one).
+	#[inline(always)] pub fn remove(&mut self, loc:(i64, i64, i64, i64)) -> Option<Inventory> { self.0.remove(&loc) }
+	
+	/// Returns an iterator over the contents of every chest, alongside its location in world-space.
+	#[inline(always)] pub fn iter(&self) -> hash_map::Iter<'_, (i64, i64, i64, i64), Inventory> { self.0.iter() }
+	
+	/// Keeps only the chests for which `f` holds.
+	#[inline(always)] pub fn retain<F:FnMut(&(i64, i64, i64, i64), &mut Inventory)->bool>(&mut self, f:F) { self.0.retain(f); }
+
+}
+
+impl FromIterator<((i64, i64, i64, i64), Inventory)> for Chests {
+	/// Collects the contents of chests, alongside their locations in world-space, into a [`Chests`].
+	fn from_iter<I:IntoIterator<Item=((i64, i64, i64, i64), Inventory)>>(iter:I) -> Self { Self(iter.into_iter().collect()) }
+}
\ No newline at end of file
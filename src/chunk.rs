@@ -1,15 +1,27 @@
 use crate::util::{CompressedChunkBytesIter, FillParams, Rect4};
-use crate::err::ChunkReadError;
-use crate::world::World;
-
-use core::fmt::{Formatter, Display, Result as FmtResult, Debug};
-use core::convert::{AsRef, From, Into};
+use crate::flood::{Adjacency, flood};
+use crate::transform::Transform;
+use crate::err::{ChunkReadError, ChunkDumpError};
+use crate::world::{Block, World};
+
+#[cfg(feature="serde")]
+use serde::{
+	de::{SeqAccess, Visitor, Error as DeserializationError},
+	Deserializer, Deserialize, Serializer, Serialize
+};
+#[cfg(feature="serde")]
+use serde_derive::{Deserialize, Serialize};
+
+use core::fmt::{Formatter, Display, Result as FmtResult, Debug, Write};
+use core::convert::{AsRef, TryFrom, From, Into};
 use core::marker::PhantomData;
-use core::iter::IntoIterator;
+use core::iter::{IntoIterator, Iterator};
 use core::default::Default;
 use core::borrow::Borrow;
 use core::mem::transmute;
-use core::ops::Deref;
+use core::ops::{Deref, FnMut};
+use std::collections::HashSet;
+use std::string::String;
 use std::sync::Arc;
 use std::vec::Vec;
 
@@ -67,51 +79,154 @@ impl<'a> CompressedChunk<'a> {
 	///  in the chunk-data is filled with air before the [`CompressedChunk`] is returned.
 	pub fn new<T:Into<Self>>(v:T) -> Self { v.into().with_remaining_filled(0) }
 	
+	/// Replaces every block of one ID in this chunk with a block of another, returning the number of blocks that were changed.
+	/// 
+	/// This works on the [`BlockGroup`]s directly, without decompressing the chunk.
+	pub fn replace(&mut self, from:u8, to:u8) -> usize {
+		if from == to { return 0; }
+		
+		let mut n      = 0;
+		let mut groups = Vec::<BlockGroup>::with_capacity(self.0.len());
+		for &group in &*self.0 {
+			let group = if group.block_id == from { n += group.span as usize; BlockGroup {block_id: to, ..group} } else { group };
+			match groups.last_mut() {
+				Some(last) if last.block_id == group.block_id && last.span as usize + group.span as usize <= u8::MAX as usize => last.span += group.span,
+				_ => groups.push(group)
+			}
+		}
+		
+		if n > 0 { self.0 = groups.into(); }
+		n
+	}
+	
+	/// Fills the area described by some [`FillParams`], but only where `predicate` holds for the block that is already there.
+	/// 
+	/// Returns the number of blocks that were changed.
+	pub fn fill_where<P:FnMut(u8)->bool>(&mut self, fill_params:FillParams, predicate:P) -> usize {
+		let mut chunk = self.decompressed();
+		let n = chunk.fill_where(fill_params, predicate);
+		if n > 0 { self.0 = BlockGroup::encode(chunk.0.iter().flatten().flatten().flatten().copied()).into(); }
+		n
+	}
+	
+	/// Returns an iterator over the location of every block of the specified ID in this chunk, in X, Y, Z, W order.
+	/// 
+	/// Runs of other blocks are skipped over whole, without decompressing the chunk.
+	pub fn find(&self, block_id:u8) -> impl Iterator<Item=(usize, usize, usize, usize)> + '_ {
+		self.0.iter()
+			.scan(0, |pos, group| { let start = *pos; *pos += group.span as usize; Some((start, group)) })
+			.filter(move |(_, group)| group.block_id == block_id)
+			.flat_map(|(start, group)| (start..start+group.span as usize).map(Chunk::location_of))
+	}
+	
+	/// Collects a sequence of [`BlockGroup`]s into a [`CompressedChunk`], filling any remaining space with air,
+	///  or fails if they hold more blocks than fit in a chunk.
+	pub(crate) fn from_groups<I:IntoIterator<Item=BlockGroup>>(groups:I) -> Result<Self, ChunkReadError> {
+		let mut ct:usize = 0;
+		
+		Ok(Self(
+			groups.into_iter()
+				.map(|group| {
+					ct += group.span as usize;
+					if ct > Chunk::HYPERVOLUME {
+						return Err(ChunkReadError::TooMuchData {
+							last_group: group,
+							excess: ct-Chunk::HYPERVOLUME
+						});
+					}
+					Ok(group)
+				}).collect::<Result<Arc<[BlockGroup]>, _>>()?,
+				PhantomData
+		).with_remaining_filled(0))
+	}
+	
+	/// Writes this chunk out as human-readable text, with one [`BlockGroup`] per line,
+	///  so that it can be read, edited, and diffed.
+	/// 
+	/// Each line holds the position of the group's first block in the chunk-data,
+	///  that block's X, Y, Z and W coordinates, the group's span and its block's name
+	///  (or `#` followed by its ID, if it is not recognized).
+	/// Lines starting with `#` are comments.
+	pub fn dump(&self) -> String {
+		let mut text = String::from("# chunk dump: one group of blocks per line\n#   start   x   y   z   w  span  block\n");
+		let mut pos  = 0;
+		for group in self.iter() {
+			let (x, y, z, w) = Chunk::location_of(pos);
+			let name = match Block::try_from(group.block_id) {
+				Ok(block) => block.as_str().to_string(),
+				Err(id)   => format!("#{id}")
+			};
+			_ = writeln!(text, "{pos:>9} {x:>3} {y:>3} {z:>3} {w:>3} {:>5}  {name}", group.span);
+			pos += group.span as usize;
+		}
+		text
+	}
+	
+	/// Reads a chunk back from [dumped](CompressedChunk::dump) text, filling any remaining space with air.
+	/// 
+	/// Only the span and block of each line are used; the positions are worked out again from the spans,
+	///  so lines can be added, removed, or resized without renumbering the rest.
+	/// Spans longer than 255 blocks are split into several groups.
+	pub fn from_dump(text:&str) -> Result<Self, ChunkDumpError> {
+		let mut groups = Vec::new();
+		let mut ct     = 0;
+		
+		for (idx, line) in text.lines().enumerate().map(|(idx, line)| (idx+1, line.trim())) {
+			if line.is_empty() || line.starts_with('#') { continue; }
+			
+			let cols = line.split_whitespace().collect::<Vec<_>>();
+			let nums = cols.iter().take(6).map(|col| col.parse::<usize>()).collect::<Result<Vec<_>, _>>();
+			let span = match nums.as_deref() {
+				Ok(&[_, _, _, _, _, span]) if span > 0 && cols.len() > 6 => span,
+				_ => return Err(ChunkDumpError::MalformedLine(idx))
+			};
+			let name = cols[6..].join(" ");
+			
+			let block_id = match name.strip_prefix('#') {
+				Some(id) => id.parse::<u8>().ok(),
+				None     => Block::from_name(&name).map(u8::from)
+			}.ok_or_else(|| ChunkDumpError::UnknownBlock {line: idx, name: name.clone()})?;
+			
+			// `ct` never passes the hypervolume, so checking against what is left can not overflow (unlike adding `span` first).
+			if span > Chunk::HYPERVOLUME-ct { return Err(ChunkDumpError::TooMuchData {line: idx, excess: span-(Chunk::HYPERVOLUME-ct)}); }
+			ct += span;
+			
+			let mut remaining = span;
+			while remaining > 0 {
+				let span = remaining.min(u8::MAX as usize);
+				groups.push(BlockGroup {block_id, span: span as u8});
+				remaining -= span;
+			}
+		}
+		
+		Ok(Self(groups.into(), PhantomData).with_remaining_filled(0))
+	}
+	
 	/// Returns an iterator over the bytes in this [`CompressedChunk`].
-	pub const fn iter_bytes(&self) -> CompressedChunkBytesIter { CompressedChunkBytesIter::new(self) }
+	pub const fn iter_bytes(&self) -> CompressedChunkBytesIter<'_> { CompressedChunkBytesIter::new(self) }
 }
 
 impl<'a> IntoIterator for &'a CompressedChunk<'a> {
 	type IntoIter = ::core::slice::Iter<'a, BlockGroup>;
 	type Item     = &'a BlockGroup;
 	
-	fn into_iter(self) -> Self::IntoIter { (&self.0).into_iter() }
+	fn into_iter(self) -> Self::IntoIter { self.0.iter() }
 }
 
 impl<'a> ChunkData for CompressedChunk<'a> {
 	/// Attempts to convert byte-sequence representing one or more [`BlockGroup`]s into a [`CompressedChunk`].
 	fn from_bytes(bytes:&[u8]) -> Result<Self, ChunkReadError> {
 		let l = bytes.len();
-		if l % 2 != 0 { return Err(ChunkReadError::BrokenIdRunlengthPair(l)); }
+		if !l.is_multiple_of(2) { return Err(ChunkReadError::BrokenIdRunlengthPair(l)); }
 		
-		let mut ct:usize = 0;
-		
-		Ok(Self(
-			bytes.chunks_exact(2)
-				.map(|pair| {
-					let pair = match pair {
-						&[block_id, span] => BlockGroup {block_id, span},
-						_ => unreachable!()
-					};
-					
-					ct += pair.span as usize;
-					if ct > Chunk::HYPERVOLUME {
-						return Err(ChunkReadError::TooMuchData {
-							last_group: pair,
-							excess: ct-Chunk::HYPERVOLUME
-						});
-					}
-					Ok(pair)
-				}).collect::<Result<Arc<[BlockGroup]>, _>>()?,
-				PhantomData
-		).with_remaining_filled(0))
+		Self::from_groups(bytes.chunks_exact(2).map(|pair| BlockGroup {block_id: pair[0], span: pair[1]}))
 	}
 	
 	fn decompressed(&self) -> Chunk {
 		let mut chunk = Chunk::filled_with(0);
 		
 		let mut pos   = 0;
-		for group in &*self {
+		for group in self.iter() {
 			let mut n = group.span;
 			while n > 0 {
 				chunk.0[pos / 8192][(pos/64) % 128][(pos/8) % 8][pos % 8] = group.block_id;
@@ -124,7 +239,16 @@ impl<'a> ChunkData for CompressedChunk<'a> {
 		chunk
 	}
 	
-	fn get_block(&self, _loc:(usize, usize, usize, usize)) -> Option<u8> { todo!() }
+	fn get_block(&self, loc:(usize, usize, usize, usize)) -> Option<u8> {
+		if loc.0 >= Chunk::WIDTH || loc.1 >= World::HEIGHT || loc.2 >= Chunk::LENGTH || loc.3 >= Chunk::WETH { return None; }
+		
+		let mut pos = Chunk::position_of(loc);
+		for group in self.iter() {
+			if pos < group.span as usize { return Some(group.block_id); }
+			pos -= group.span as usize;
+		}
+		None
+	}
 }
 
 impl<'a> Default for CompressedChunk<'a> {
@@ -168,17 +292,72 @@ impl<'a> From<Vec<BlockGroup>> for CompressedChunk<'a> {
 	fn from(v:Vec<BlockGroup>) -> Self { Self(v.into(), PhantomData) }
 }
 
+/// Serializes as a sequence of `{block, span}` groups in human-readable formats, and as a byte-string otherwise.
+#[cfg(feature="serde")]
+impl<'a> Serialize for CompressedChunk<'a> {
+	fn serialize<S:Serializer>(&self, s:S) -> Result<S::Ok, S::Error> {
+		if s.is_human_readable() { s.collect_seq(self.iter()) } else { s.serialize_bytes(&self.iter_bytes().collect::<Vec<_>>()) }
+	}
+}
+
+#[cfg(feature="serde")]
+impl<'de, 'a> Deserialize<'de> for CompressedChunk<'a> {
+	fn deserialize<D:Deserializer<'de>>(d:D) -> Result<Self, D::Error> {
+		if d.is_human_readable() {
+			Self::from_groups(Vec::<BlockGroup>::deserialize(d)?).map_err(D::Error::custom)
+		} else {
+			d.deserialize_byte_buf(CompressedChunkBytesVisitor)
+		}
+	}
+}
+
+/// Deserializes a [`CompressedChunk`] from a byte-string (or a sequence of bytes).
+#[cfg(feature="serde")]
+#[doc(hidden)]
+struct CompressedChunkBytesVisitor;
+
+#[cfg(feature="serde")]
+impl<'de> Visitor<'de> for CompressedChunkBytesVisitor {
+	type Value = CompressedChunk<'static>;
+	
+	fn expecting(&self, f:&mut Formatter) -> FmtResult { f.write_str("the bytes of a compressed chunk") }
+	
+	fn visit_bytes<E:DeserializationError>(self, v:&[u8]) -> Result<Self::Value, E> { CompressedChunk::from_bytes(v).map_err(E::custom) }
+	
+	fn visit_seq<A:SeqAccess<'de>>(self, mut seq:A) -> Result<Self::Value, A::Error> {
+		let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+		while let Some(b) = seq.next_element::<u8>()? { bytes.push(b); }
+		self.visit_bytes(&bytes)
+	}
+}
+
 
 
 /// A group of blocks in a chunk.  
 ///  (A block ID and length pair.)
 #[derive(PartialEq, Default, Clone, Debug, Hash, Copy, Eq)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
 #[repr(C)]
 pub struct BlockGroup {
+	#[cfg_attr(feature="serde", serde(rename="block"))]
 	pub block_id:u8,
 	pub span:u8
 }
 
+impl BlockGroup {
+	/// Run-length encodes a sequence of block IDs into [`BlockGroup`]s.
+	pub fn encode<I:IntoIterator<Item=u8>>(blocks:I) -> Vec<Self> {
+		let mut groups = Vec::<Self>::new();
+		for block_id in blocks {
+			match groups.last_mut() {
+				Some(group) if group.block_id == block_id && group.span < u8::MAX => group.span += 1,
+				_ => groups.push(Self {block_id, span: 1})
+			}
+		}
+		groups
+	}
+}
+
 impl Display for BlockGroup {
 	fn fmt(&self, f:&mut Formatter) -> FmtResult {
 		write!(f, "[{} * block#{}]", self.span, self.block_id)
@@ -189,8 +368,8 @@ impl From<[u8; 2]> for BlockGroup {
 	fn from(v:[u8; 2]) -> Self { unsafe { transmute(v) } }
 }
 
-impl Into<[u8; 2]> for BlockGroup {
-	fn into(self) -> [u8; 2] { unsafe { transmute(self) } }
+impl From<BlockGroup> for [u8; 2] {
+	fn from(v:BlockGroup) -> Self { unsafe { transmute(v) } }
 }
 
 
@@ -213,50 +392,92 @@ impl Chunk {
 	/// Creates a new [`Chunk`] filled entirely with a block of the specified ID.
 	pub const fn filled_with(block_id:u8) -> Self { Self([[[[block_id; Self::WETH]; Self::LENGTH]; World::HEIGHT]; Self::WIDTH]) }
 	
-	pub fn compress(&self) -> CompressedChunk {
-		let mut cbt_ct = 0;
-		let mut data   = Vec::new();
-		let mut cbt    = None;
-		
-		let mut pos = 0;
-		loop {
-			if pos >= Self::HYPERVOLUME { break; }
-			
-			let id = self.0[pos / 8192][(pos/64) % 128][(pos/8) % 8][pos % 8];
-			match cbt {
-				Some(pbt) if pbt == id => {
-					cbt_ct += 1;
-					if cbt_ct == 255 || pos == Self::HYPERVOLUME-1 {
-						data.push(BlockGroup {block_id: id, span: cbt_ct});
-						cbt_ct = 0;
-					}
-				}
-				
-				Some(_) | None => {
-					if cbt_ct > 0 {
-						data.push(BlockGroup {block_id: unsafe { cbt.unwrap_unchecked() }, span: cbt_ct});
-					}
-					
-					cbt_ct = 1;
-					cbt = Some(id);
-				}
-			}
-			
-			pos += 1;
-		}
-		
-		CompressedChunk(data.into(), PhantomData)
+	/// Converts an index into chunk-data (in X, Y, Z, W order) into a location within a chunk.
+	#[inline(always)] pub(crate) const fn location_of(pos:usize) -> (usize, usize, usize, usize) {
+		(pos / (Self::WETH*Self::LENGTH*World::HEIGHT), (pos / (Self::WETH*Self::LENGTH)) % World::HEIGHT, (pos / Self::WETH) % Self::LENGTH, pos % Self::WETH)
+	}
+	
+	/// Converts a location within a chunk into an index into chunk-data (in X, Y, Z, W order).
+	#[inline(always)] pub(crate) const fn position_of(loc:(usize, usize, usize, usize)) -> usize {
+		((loc.0*World::HEIGHT + loc.1)*Self::LENGTH + loc.2)*Self::WETH + loc.3
+	}
+	
+	/// Compresses this chunk-data into a [`CompressedChunk`].
+	pub fn compress(&self) -> CompressedChunk<'_> {
+		CompressedChunk(BlockGroup::encode(self.0.iter().flatten().flatten().flatten().copied()).into(), PhantomData)
 	}
 	
 	/// Fills the specified area from point `a` to point `b` with a block of the specified ID.
 	pub fn fill_with_params(&mut self, mut fill_params:FillParams) {
-		#[inline(always)] const fn sort(a:usize, b:usize) -> (usize, usize) { if a > b { (b, a) } else { (a, b) } }
+		let determiner = fill_params.determiner.as_mut();
+		self.fill_rect(fill_params.rect, |loc, _| determiner(loc, (loc.0 as i64, loc.1 as i64, loc.2 as i64, loc.3 as i64)));
+	}
+	
+	/// Fills the area described by some [`FillParams`], but only where `predicate` holds for the block that is already there.
+	/// 
+	/// Returns the number of blocks that were changed.
+	pub fn fill_where<P:FnMut(u8)->bool>(&mut self, mut fill_params:FillParams, mut predicate:P) -> usize {
+		let determiner = fill_params.determiner.as_mut();
+		self.fill_rect(fill_params.rect, |loc, existing| {
+			if predicate(existing) { determiner(loc, (loc.0 as i64, loc.1 as i64, loc.2 as i64, loc.3 as i64)) } else { None }
+		})
+	}
+	
+	/// Returns an iterator over the location of every block of the specified ID in this chunk, in X, Y, Z, W order.
+	pub fn find(&self, block_id:u8) -> impl Iterator<Item=(usize, usize, usize, usize)> + '_ {
+		self.0.iter().flatten().flatten().flatten()
+			.enumerate()
+			.filter(move |&(_, &id)| id == block_id)
+			.map(|(pos, _)| Self::location_of(pos))
+	}
+	
+	/// Finds every block connected to the block at `start` through blocks for which `predicate` holds,
+	///  in the order they are reached.
+	/// 
+	/// Returns nothing if `predicate` does not hold for the block at `start`, or it is outside of the chunk.
+	pub fn connected<P:FnMut(u8)->bool>(&self, start:(usize, usize, usize, usize), adjacency:Adjacency, predicate:P) -> Vec<(usize, usize, usize, usize)> {
+		self.connected_from(start, adjacency, predicate, &mut HashSet::new())
+	}
+	
+	/// Splits every block for which `predicate` holds into groups of blocks which are connected to each other,
+	///  in X, Y, Z, W order of the first block of each group.
+	pub fn components<P:FnMut(u8)->bool>(&self, adjacency:Adjacency, mut predicate:P) -> Vec<Vec<(usize, usize, usize, usize)>> {
+		let mut seen       = HashSet::new();
+		let mut components = Vec::new();
+		for pos in 0..Self::HYPERVOLUME {
+			let loc = Self::location_of(pos);
+			if seen.contains(&(loc.0 as i64, loc.1 as i64, loc.2 as i64, loc.3 as i64)) { continue; }
+			
+			let component = self.connected_from(loc, adjacency, &mut predicate, &mut seen);
+			if !component.is_empty() { components.push(component); }
+		}
+		components
+	}
+	
+	/// Whether this chunk contains any block of the specified ID.
+	pub fn contains(&self, block_id:u8) -> bool { self.0.iter().flatten().flatten().flatten().any(|&id| id == block_id) }
+	
+	/// Replaces every block of one ID in this chunk with a block of another, returning the number of blocks that were changed.
+	pub fn replace(&mut self, from:u8, to:u8) -> usize {
+		if from == to { return 0; }
 		
-		let (sx, dx) = sort(fill_params.rect.start.0, fill_params.rect.end.0);
-		let (sy, dy) = sort(fill_params.rect.start.1, fill_params.rect.end.1);
-		let (sz, dz) = sort(fill_params.rect.start.2, fill_params.rect.end.2);
-		let (sw, dw) = sort(fill_params.rect.start.3, fill_params.rect.end.3);
+		let mut n = 0;
+		for block_id in self.0.iter_mut().flatten().flatten().flatten().filter(|block_id| **block_id == from) {
+			*block_id = to;
+			n += 1;
+		}
+		n
+	}
+	
+	/// Sets every block in the specified area (clipped to the chunk) to the ID chosen by `determiner` for its location (and the block already there),
+	///  leaving blocks it chooses nothing for as they are.
+	/// 
+	/// Returns the number of blocks that were changed.
+	pub(crate) fn fill_rect<F:FnMut((usize, usize, usize, usize), u8)->Option<u8>>(&mut self, rect:Rect4, mut determiner:F) -> usize {
+		let bounds = Rect4::new((0, 0, 0, 0), (Self::WIDTH-1, World::HEIGHT-1, Self::LENGTH-1, Self::WETH-1));
+		let Some(Rect4 {start: (sx, sy, sz, sw), end: (dx, dy, dz, dw)}) = rect.intersection(&bounds) else { return 0; };
 		
+		let mut changed = 0;
 		let mut x = sx;
 		loop {
 			let mut y = sy;
@@ -265,7 +486,11 @@ impl Chunk {
 				loop {
 					let mut w = sw;
 					loop {
-						self.0[x][y][z][w] = fill_params.determiner.as_mut()((x, y, z, w));
+						let slot = &mut self.0[x][y][z][w];
+						if let Some(block_id) = determiner((x, y, z, w), *slot) && block_id != *slot {
+							*slot = block_id;
+							changed += 1;
+						}
 						
 						if w >= dw { break; }
 						w += 1;
@@ -282,11 +507,67 @@ impl Chunk {
 			if x >= dx { break; }
 			x += 1;
 		}
+		changed
+	}
+	
+	/// Sets the ID of a block at a given coordinate in 4D space, returning the ID of the block it replaced.
+	/// 
+	/// Returns [`None`] (and does nothing) if the coordinate is outside of the chunk.
+	pub fn set_block(&mut self, loc:(usize, usize, usize, usize), block_id:u8) -> Option<u8> {
+		let slot = self.0.get_mut(loc.0)?.get_mut(loc.1)?.get_mut(loc.2)?.get_mut(loc.3)?;
+		let prev = *slot;
+		*slot = block_id;
+		Some(prev)
 	}
 	
+	/// Creates a copy of this chunk which has been rotated and/or reflected (about its center).
+	/// 
+	/// Returns [`None`] if the transformation would change the shape of the chunk,
+	///  which is the case for quarter-turns in planes which include the Y axis.
+	pub fn transformed(&self, transform:Transform) -> Option<Self> {
+		const SIZE:(usize, usize, usize, usize) = (Chunk::WIDTH, World::HEIGHT, Chunk::LENGTH, Chunk::WETH);
+		if transform.apply_size(SIZE) != SIZE { return None; }
+		
+		let mut out = Self::filled_with(0);
+		for x in 0..Self::WIDTH { for y in 0..World::HEIGHT { for z in 0..Self::LENGTH { for w in 0..Self::WETH {
+			let (tx, ty, tz, tw) = transform.apply((x, y, z, w), SIZE);
+			out.0[tx][ty][tz][tw] = self.0[x][y][z][w];
+		} } } }
+		Some(out)
+	}
+	
+	/// Fills the specified area with a block of the specified ID.
 	pub fn fill(&mut self, block_id:u8, rect:Rect4) {
 		self.fill_with_params(FillParams::solid(block_id, rect));
 	}
+	
+	/// Flood fills from `start` like [`Chunk::connected`], skipping (and adding to) the `seen` locations.
+	fn connected_from<P:FnMut(u8)->bool>(&self, start:(usize, usize, usize, usize), adjacency:Adjacency, mut predicate:P, seen:&mut HashSet<(i64, i64, i64, i64)>) -> Vec<(usize, usize, usize, usize)> {
+		let start = (start.0 as i64, start.1 as i64, start.2 as i64, start.3 as i64);
+		flood(start, adjacency, |(x, y, z, w)| {
+			x >= 0 && y >= 0 && z >= 0 && w >= 0 && self.get_block((x as usize, y as usize, z as usize, w as usize)).is_some_and(&mut predicate)
+		}, seen).into_iter().map(|(x, y, z, w)| (x as usize, y as usize, z as usize, w as usize)).collect()
+	}
+	
+	/// Run-length encodes the exclusive-or of this chunk's blocks and another's,
+	///  which is mostly zeroes when the two chunks are similar.
+	pub(crate) fn delta(&self, other:&Self) -> Vec<BlockGroup> {
+		let a = self.0.iter().flatten().flatten().flatten();
+		let b = other.0.iter().flatten().flatten().flatten();
+		BlockGroup::encode(a.zip(b).map(|(a, b)| a ^ b))
+	}
+	
+	/// Applies a delta created by [`Chunk::delta`], turning either chunk it was created from into the other.
+	pub(crate) fn apply_delta(&mut self, delta:&[BlockGroup]) {
+		let mut blocks = self.0.iter_mut().flatten().flatten().flatten();
+		for group in delta {
+			if group.block_id == 0 {
+				blocks.nth(group.span as usize - 1);
+				continue;
+			}
+			for block_id in blocks.by_ref().take(group.span as usize) { *block_id ^= group.block_id; }
+		}
+	}
 }
 
 impl ChunkData for Chunk {
@@ -303,4 +584,15 @@ impl ChunkData for Chunk {
 
 impl Default for Chunk {
 	fn default() -> Self { Self::filled_with(0) }
+}
+
+/// Serializes as the equivalent [`CompressedChunk`].
+#[cfg(feature="serde")]
+impl Serialize for Chunk {
+	fn serialize<S:Serializer>(&self, s:S) -> Result<S::Ok, S::Error> { self.compress().serialize(s) }
+}
+
+#[cfg(feature="serde")]
+impl<'de> Deserialize<'de> for Chunk {
+	fn deserialize<D:Deserializer<'de>>(d:D) -> Result<Self, D::Error> { Ok(CompressedChunk::deserialize(d)?.decompressed()) }
 }
\ No newline at end of file
@@ -1,10 +1,19 @@
+use crate::world::Block;
+
+#[cfg(feature="serde")]
 use serde::{
-	de::{Unexpected, Visitor, Error as DeserializationError},
-	Deserializer, Deserialize
+	de::{EnumAccess, IgnoredAny, MapAccess, Unexpected, VariantAccess, Visitor, Error as DeserializationError},
+	Deserializer, Deserialize, Serializer, Serialize
 };
+#[cfg(feature="serde")]
 use serde_derive::{Deserialize, Serialize};
 
 use core::fmt::{Formatter, Display, Result as FmtResult};
+use core::convert::{TryFrom, From};
+use core::mem::transmute;
+#[cfg(feature="serde")]
+use std::string::String;
+use core::{iter, slice};
 
 
 
@@ -20,56 +29,109 @@ pub trait Collectable:Sized {
 
 
 
-/// Test
+/// Deserializes a [`CollectableType`] from its name (or an alias), its index, or an enum-like map.
+#[cfg(feature="serde")]
 #[doc(hidden)]
 struct CollectableTypeVisitor;
 
+#[cfg(feature="serde")]
 impl<'de> Visitor<'de> for CollectableTypeVisitor {
 	type Value = CollectableType;
 	
-	fn expecting(&self, f:&mut std::fmt::Formatter) -> std::fmt::Result { f.write_str("the name of a CollectableType (variant)") }
+	fn expecting(&self, f:&mut Formatter) -> FmtResult { f.write_str("the name or index of a CollectableType (variant)") }
+	
 	fn visit_str<E:DeserializationError>(self, v:&str) -> Result<Self::Value, E> {
-		if v.eq_ignore_ascii_case("item") || v.eq_ignore_ascii_case("material") { return Ok(CollectableType::Item); }
-		match v {
-			_ if v.eq_ignore_ascii_case("block") => Ok(CollectableType::Block),
-			_ if v.eq_ignore_ascii_case("tool")  => Ok(CollectableType::Tool),
-			_ => Err(E::invalid_value(Unexpected::Other("unrecognized value"), &self))
-		}
+		CollectableType::from_name(v).ok_or_else(|| E::unknown_variant(v, CollectableType::NAMES))
+	}
+	
+	fn visit_u64<E:DeserializationError>(self, v:u64) -> Result<Self::Value, E> {
+		usize::try_from(v).ok().and_then(|idx| CollectableType::ALL.get(idx).copied())
+			.ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+	}
+	
+	fn visit_i64<E:DeserializationError>(self, v:i64) -> Result<Self::Value, E> {
+		u64::try_from(v).map_err(|_| E::invalid_value(Unexpected::Signed(v), &self)).and_then(|v| self.visit_u64(v))
+	}
+	
+	fn visit_map<A:MapAccess<'de>>(self, mut map:A) -> Result<Self::Value, A::Error> {
+		let Some(name) = map.next_key::<String>()? else { return Err(A::Error::invalid_length(0, &self)) };
+		map.next_value::<IgnoredAny>()?;
+		self.visit_str(&name)
+	}
+	
+	fn visit_enum<A:EnumAccess<'de>>(self, data:A) -> Result<Self::Value, A::Error> {
+		let (typ, variant) = data.variant::<CollectableType>()?;
+		variant.unit_variant()?;
+		Ok(typ)
 	}
 }
 
 
 
 /// The type of collectable that something is.
-#[derive(Serialize, Clone, Debug, Copy)]
-#[serde(rename_all="lowercase")]
+/// 
+/// Human-readable formats store a type by its (lowercase) name, and binary formats by its index;
+/// either is accepted when deserializing from a self-describing format, as is the alias `material` for [`CollectableType::Item`].
+#[derive(PartialEq, Clone, Debug, Copy, Hash, Eq)]
 pub enum CollectableType {
 	Block,
-	#[serde(rename="material")]
 	Item,
 	Tool
 }
 
+#[cfg(feature="serde")]
+impl Serialize for CollectableType {
+	fn serialize<S:Serializer>(&self, s:S) -> Result<S::Ok, S::Error> {
+		if s.is_human_readable() { s.serialize_str(self.as_str()) } else { s.serialize_u8(*self as u8) }
+	}
+}
+
+#[cfg(feature="serde")]
 impl<'de> Deserialize<'de> for CollectableType {
-	fn deserialize<D:Deserializer<'de>>(d:D) -> Result<Self, D::Error> { d.deserialize_enum("CollectableType", &["Block", "Item", "Tool"], CollectableTypeVisitor) }
+	fn deserialize<D:Deserializer<'de>>(d:D) -> Result<Self, D::Error> {
+		if d.is_human_readable() { d.deserialize_any(CollectableTypeVisitor) } else { d.deserialize_u8(CollectableTypeVisitor) }
+	}
 }
 
-impl Display for CollectableType {
-	fn fmt(&self, f:&mut Formatter) -> FmtResult {
-		f.write_str(match self {
-			Self::Block => "block",
-			Self::Item  => "material",
-			Self::Tool  => "tool"
-		})
+impl CollectableType {
+	/// Every type of collectable.
+	pub const ALL:[Self; 3] = [Self::Block, Self::Item, Self::Tool];
+	
+	/// The names of every type of collectable, in the same order as [`CollectableType::ALL`].
+	pub const NAMES:&'static [&'static str] = &["block", "item", "tool"];
+	
+	/// The name of this type of collectable.
+	#[inline(always)] pub const fn as_str(&self) -> &'static str { Self::NAMES[*self as usize] }
+	
+	/// Interprets the name of a type of collectable, ignoring case and accepting `material` for [`CollectableType::Item`].
+	pub fn from_name(name:&str) -> Option<Self> {
+		if name.eq_ignore_ascii_case("material") { return Some(Self::Item); }
+		Self::ALL.into_iter().find(|typ| name.eq_ignore_ascii_case(typ.as_str()))
+	}
+	
+	/// The most collectables of this type which fit in a single inventory slot.
+	/// 
+	/// These limits are this crate's own choice, not values read from the game.
+	pub const fn stack_limit(&self) -> u32 {
+		match self {
+			Self::Block | Self::Item => 64,
+			Self::Tool               => 1
+		}
 	}
 }
 
+impl Display for CollectableType {
+	fn fmt(&self, f:&mut Formatter) -> FmtResult { f.write_str(self.as_str()) }
+}
+
 
 
 /// The standard set of items in 4D Miner.
 #[non_exhaustive]
-#[derive(Deserialize, Serialize, Clone, Debug, Hash, Copy)]
-#[serde(rename_all="snake_case")]
+#[derive(PartialEq, Clone, Debug, Hash, Copy, Eq)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature="serde", serde(rename_all="snake_case"))]
+#[repr(u8)]
 pub enum Item {
 	Stick,
 	Hammer,
@@ -95,6 +157,17 @@ pub enum Item {
 }
 
 impl Item {
+	/// Every item, in index order.
+	pub const ALL:[Self; 21] = [
+		Self::Stick, Self::Hammer, Self::IronPick, Self::DeadlyPick, Self::IronAxe, Self::DeadlyAxe,
+		Self::Ultrahammer, Self::SolenoidCollector, Self::Rock, Self::Hypersilk, Self::IronBars, Self::DeadlyBars,
+		Self::SolenoidBars, Self::Compass, Self::Glasses, Self::KleinBottle, Self::HealthPotion, Self::RedLens,
+		Self::GreenLens, Self::BlueLens, Self::Alidade
+	];
+	
+	/// Returns an iterator over every item, in index order.
+	#[inline(always)] pub fn iter() -> iter::Copied<slice::Iter<'static, Self>> { Self::ALL.iter().copied() }
+	
 	#[inline(always)] pub const fn as_str(&self) -> &'static str {
 		match self {
 			Self::SolenoidCollector => "Solenoid Collector",
@@ -120,11 +193,9 @@ impl Item {
 			Self::Rock              => "Rock"
 		}
 	}
-}
-
-impl Collectable for Item {
-	fn name(&self) -> &str { self.as_str() }
-	fn typ(&self)  -> CollectableType {
+	
+	/// The type of collectable an item is.
+	pub const fn typ(&self) -> CollectableType {
 		match self {
 			Self::SolenoidCollector => CollectableType::Tool,
 			Self::SolenoidBars      => CollectableType::Item,
@@ -151,6 +222,132 @@ impl Collectable for Item {
 	}
 }
 
+impl Collectable for Item {
+	fn name(&self) -> &str { self.as_str() }
+	#[inline(always)] fn typ(&self)  -> CollectableType { Item::typ(self) }
+}
+
+impl TryFrom<u8> for Item {
+	type Error = u8;
+	
+	/// Attempts to interpret an item's index as an [`Item`], giving back the index if it is not recognized.
+	fn try_from(v:u8) -> Result<Self, Self::Error> {
+		if v <= Self::Alidade as u8 { Ok(unsafe { transmute::<u8, Self>(v) }) } else { Err(v) }
+	}
+}
+
 impl Display for Item {
 	fn fmt(&self, f:&mut Formatter) -> FmtResult { f.write_str(self.as_str()) }
+}
+
+
+
+/// Any collectable: either a [`Block`] or an [`Item`].
+#[derive(PartialEq, Clone, Debug, Copy, Hash, Eq)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature="serde", serde(rename_all="snake_case"))]
+pub enum AnyCollectable {
+	Block(Block),
+	Item(Item)
+}
+
+impl AnyCollectable {
+	/// The index of the first item; blocks are indexed by their block ID.
+	pub const ITEM_INDEX_OFFSET:u16 = 256;
+	
+	/// The index of this collectable in this crate's numbering of every block and item.
+	pub const fn index(&self) -> u16 {
+		match self {
+			Self::Block(block) => *block as u16,
+			Self::Item(item)   => Self::ITEM_INDEX_OFFSET + *item as u16
+		}
+	}
+	
+	/// Finds the collectable with an index in this crate's numbering, if it is recognized.
+	pub fn from_index(index:u16) -> Option<Self> {
+		match index.checked_sub(Self::ITEM_INDEX_OFFSET) {
+			None        => Block::try_from(index as u8).ok().map(Self::Block),
+			Some(index) => Item::try_from(u8::try_from(index).ok()?).ok().map(Self::Item)
+		}
+	}
+	
+	/// The display name of this collectable.
+	#[inline(always)] pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Block(block) => block.as_str(),
+			Self::Item(item)   => item.as_str()
+		}
+	}
+	
+	/// The type of collectable this is.
+	pub const fn typ(&self) -> CollectableType {
+		match self {
+			Self::Block(block) => block.typ(),
+			Self::Item(item)   => item.typ()
+		}
+	}
+}
+
+impl Collectable for AnyCollectable {
+	fn name(&self) -> &str { self.as_str() }
+	#[inline(always)] fn typ(&self)  -> CollectableType { AnyCollectable::typ(self) }
+}
+
+impl Display for AnyCollectable {
+	fn fmt(&self, f:&mut Formatter) -> FmtResult { f.write_str(self.name()) }
+}
+
+impl From<Block> for AnyCollectable {
+	#[inline(always)] fn from(v:Block) -> Self { Self::Block(v) }
+}
+
+impl From<Item> for AnyCollectable {
+	#[inline(always)] fn from(v:Item) -> Self { Self::Item(v) }
+}
+
+
+
+/// The index of a collectable in this crate's [numbering](AnyCollectable::index) of every block and item.
+/// 
+/// Blocks keep their block ID, and items are numbered from [`AnyCollectable::ITEM_INDEX_OFFSET`].  
+/// This numbering is internal to this crate, and is not the one the game uses for items.  
+/// Indices serialize as a plain integer, so they are a compact way of referring to any block or item.
+#[derive(PartialOrd, PartialEq, Clone, Debug, Copy, Hash, Ord, Eq)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature="serde", serde(transparent))]
+#[repr(transparent)]
+pub struct CollectableIndex(u16);
+
+impl CollectableIndex {
+	/// Creates a new [`CollectableIndex`] from an index, which may not be recognized.
+	#[inline(always)] pub const fn new(index:u16) -> Self { Self(index) }
+	
+	/// The index itself.
+	#[inline(always)] pub const fn get(&self) -> u16 { self.0 }
+	
+	/// The collectable with this index, if it is recognized.
+	#[inline(always)] pub fn collectable(&self) -> Option<AnyCollectable> { AnyCollectable::from_index(self.0) }
+}
+
+impl Display for CollectableIndex {
+	fn fmt(&self, f:&mut Formatter) -> FmtResult { write!(f, "#{}", self.0) }
+}
+
+impl From<AnyCollectable> for CollectableIndex {
+	#[inline(always)] fn from(v:AnyCollectable) -> Self { Self(v.index()) }
+}
+
+impl From<Block> for CollectableIndex {
+	#[inline(always)] fn from(v:Block) -> Self { AnyCollectable::Block(v).into() }
+}
+
+impl From<Item> for CollectableIndex {
+	#[inline(always)] fn from(v:Item) -> Self { AnyCollectable::Item(v).into() }
+}
+
+impl TryFrom<CollectableIndex> for AnyCollectable {
+	type Error = CollectableIndex;
+	
+	/// Attempts to find the collectable with an index, giving back the index if it is not recognized.
+	fn try_from(v:CollectableIndex) -> Result<Self, Self::Error> { v.collectable().ok_or(v) }
 }
\ No newline at end of file
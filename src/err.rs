@@ -31,4 +31,118 @@ impl Display for ChunkReadError {
 
 impl Error for ChunkReadError {
 	fn description(&self) -> &'static str { "a chunk-reading error occurred" }
+}
+
+
+
+#[non_exhaustive]
+#[derive(PartialEq, Clone, Debug, Eq)]
+pub enum VoxError {
+	/// The data did not begin with the `VOX ` magic number.
+	InvalidMagic,
+	/// The data ended partway through a structure starting at the given offset.
+	Truncated(usize),
+	/// The data did not contain a model (a `SIZE` chunk followed by an `XYZI` chunk).
+	MissingModel,
+	/// The requested region is larger than a `.vox` model can be along an axis.
+	TooLarge {
+		/// The size of the requested region, along the X, Y and Z axes of the model.
+		size:(usize, usize, usize)
+	}
+}
+
+impl Display for VoxError {
+	#[allow(deprecated)]
+	fn fmt(&self, f:&mut Formatter) -> FmtResult {
+		write!(f, "{}: {}", self.description(), match self {
+			Self::InvalidMagic      => "the data does not start with \"VOX \"".to_string(),
+			Self::Truncated(o)      => format!("the data ended unexpectedly (in the structure at offset {o})"),
+			Self::MissingModel      => "no model was found in the data".to_string(),
+			Self::TooLarge {size}   => format!("a model can be, at most, 256 voxels along each axis, but the region is {}*{}*{}", size.0, size.1, size.2)
+		})
+	}
+}
+
+impl Error for VoxError {
+	fn description(&self) -> &'static str { "a .vox-processing error occurred" }
+}
+
+
+
+#[non_exhaustive]
+#[derive(PartialEq, Clone, Debug, Eq)]
+pub enum SchematicReadError {
+	/// The data did not begin with the schematic magic number.
+	InvalidMagic,
+	/// The data was written with a version of the format which is not supported.
+	UnsupportedVersion(u8),
+	/// The data ended partway through a structure starting at the given offset.
+	Truncated(usize),
+	/// The block groups did not describe exactly as many blocks as the schematic holds.
+	SizeMismatch {
+		/// How many blocks the schematic holds.
+		expected:usize,
+		/// How many blocks the block groups described.
+		found:usize
+	},
+	/// The size along the X, Y, Z and W axes holds more blocks than can be counted.
+	TooLarge((usize, usize, usize, usize)),
+	/// The data continued past the end of the block groups, from the given offset.
+	TrailingData(usize)
+}
+
+impl Display for SchematicReadError {
+	#[allow(deprecated)]
+	fn fmt(&self, f:&mut Formatter) -> FmtResult {
+		write!(f, "{}: {}", self.description(), match self {
+			Self::InvalidMagic                    => "the data does not start with \"FDMS\"".to_string(),
+			Self::UnsupportedVersion(v)           => format!("version {v} of the format is not supported"),
+			Self::Truncated(o)                    => format!("the data ended unexpectedly (in the structure at offset {o})"),
+			Self::SizeMismatch {expected, found}  => format!("data for {expected} blocks was expected, but data for {found} block(s) was found"),
+			Self::TooLarge(s)                     => format!("a schematic of {}*{}*{}*{} blocks is too large", s.0, s.1, s.2, s.3),
+			Self::TrailingData(o)                 => format!("unexpected data was found after the block groups (at offset {o})")
+		})
+	}
+}
+
+impl Error for SchematicReadError {
+	fn description(&self) -> &'static str { "a schematic-reading error occurred" }
+}
+
+
+
+#[non_exhaustive]
+#[derive(PartialEq, Clone, Debug, Eq)]
+pub enum ChunkDumpError {
+	/// The (1-based) line did not hold a run in the expected columns.
+	MalformedLine(usize),
+	/// A run named a block which is not recognized.
+	UnknownBlock {
+		/// The (1-based) line of the run.
+		line:usize,
+		/// The name, as it was written.
+		name:String
+	},
+	/// The runs held more blocks than fit in a chunk.
+	TooMuchData {
+		/// The (1-based) line of the first run that did not fit.
+		line:usize,
+		/// How many additional blocks the runs held.
+		excess:usize
+	}
+}
+
+impl Display for ChunkDumpError {
+	#[allow(deprecated)]
+	fn fmt(&self, f:&mut Formatter) -> FmtResult {
+		write!(f, "{}: {}", self.description(), match self {
+			Self::MalformedLine(l)           => format!("line {l} is not a run of blocks"),
+			Self::UnknownBlock {line, name}  => format!("the block \"{name}\" on line {line} is not recognized"),
+			Self::TooMuchData {line, excess} => format!("data for, at most, {} blocks was expected, but data for {} more block(s) was found (from line {})", Chunk::HYPERVOLUME, excess, line)
+		})
+	}
+}
+
+impl Error for ChunkDumpError {
+	fn description(&self) -> &'static str { "a chunk-dump-parsing error occurred" }
 }
\ No newline at end of file
@@ -0,0 +1,72 @@
+use std::collections::{HashSet, VecDeque};
+use core::iter::Iterator;
+use core::ops::FnMut;
+use std::vec::Vec;
+
+
+
+
+
+/// Which neighbours of a block count as touching it, for flood fills and connected components.
+#[derive(PartialEq, Default, Clone, Debug, Copy, Hash, Eq)]
+pub enum Adjacency {
+	/// The 8 blocks which share a face (a cube) with the block.
+	#[default]
+	Faces,
+	/// The 32 blocks which share a face or an edge (a square) with the block.
+	Edges,
+	/// All 80 blocks which share any corner with the block.
+	Corners
+}
+
+impl Adjacency {
+	/// The number of axes along which a neighbour can be offset from the block.
+	#[inline(always)] const fn max_axes(&self) -> usize {
+		match self {
+			Self::Faces   => 1,
+			Self::Edges   => 2,
+			Self::Corners => 4
+		}
+	}
+	
+	/// Returns an iterator over the offset of every neighbour of a block.
+	pub fn offsets(&self) -> impl Iterator<Item=(i64, i64, i64, i64)> {
+		let max_axes = self.max_axes();
+		(0..81).map(|n| (n % 3 - 1, n / 3 % 3 - 1, n / 9 % 3 - 1, n / 27 % 3 - 1))
+			.filter(move |&(x, y, z, w)| {
+				let axes = [x, y, z, w].iter().filter(|&&d| d != 0).count();
+				axes > 0 && axes <= max_axes
+			})
+	}
+	
+	/// Returns an iterator over every neighbour of a location.
+	pub fn neighbours(&self, (x, y, z, w):(i64, i64, i64, i64)) -> impl Iterator<Item=(i64, i64, i64, i64)> {
+		self.offsets().map(move |d| (x+d.0, y+d.1, z+d.2, w+d.3))
+	}
+}
+
+
+
+/// Finds every location connected to `start` through locations which are `member`s (including `start` itself, if it is one),
+///  in the order they are reached.
+/// 
+/// Locations already in `seen` are treated as if they were not members, and every location that is found is added to it.
+pub(crate) fn flood<F:FnMut((i64, i64, i64, i64))->bool>(start:(i64, i64, i64, i64), adjacency:Adjacency, mut member:F, seen:&mut HashSet<(i64, i64, i64, i64)>) -> Vec<(i64, i64, i64, i64)> {
+	if seen.contains(&start) || !member(start) { return Vec::new(); }
+	seen.insert(start);
+	
+	let offsets   = adjacency.offsets().collect::<Vec<_>>();
+	let mut found = vec![start];
+	let mut queue = VecDeque::from([start]);
+	while let Some((x, y, z, w)) = queue.pop_front() {
+		for d in &offsets {
+			let next = (x+d.0, y+d.1, z+d.2, w+d.3);
+			if !seen.contains(&next) && member(next) {
+				seen.insert(next);
+				found.push(next);
+				queue.push_back(next);
+			}
+		}
+	}
+	found
+}
\ No newline at end of file
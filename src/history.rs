@@ -0,0 +1,180 @@
+use crate::chunk::{CompressedChunk, BlockGroup, ChunkData, Chunk};
+use crate::inventory::{ItemStack, Inventory};
+
+use std::collections::{HashMap, VecDeque};
+use core::default::Default;
+use core::mem::size_of;
+use std::vec::Vec;
+
+
+
+
+
+/// A bounded record of the edits made to a [`World`](crate::world::World), which can be undone and redone.
+/// 
+/// Each edit is stored as the difference it made to every chunk it touched, run-length encoded
+///  like a [`CompressedChunk`],
+///  alongside the contents of any chests it removed, so that undoing it brings them back.
+/// Once the edits take up more than the memory budget, the oldest are forgotten.
+/// 
+/// While an edit is in progress, the (compressed) contents of every chunk it has touched count towards the budget as well;
+///  if they alone exceed it, the edit can not be recorded, and the whole history is forgotten once it is finished.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+	undo:VecDeque<Edit>,
+	redo:Vec<Edit>,
+	budget:usize,
+	used:usize,
+	/// The (compressed) contents of every chunk touched by the edit in progress, from before it was touched.
+	pending:HashMap<(i64, i64, i64), Option<Vec<BlockGroup>>>,
+	/// The number of bytes used by `pending`.
+	pending_used:usize,
+	/// Whether the edit in progress touched too much to be recorded.
+	overflowed:bool,
+	/// How many (nested) edits are in progress.
+	depth:usize
+}
+
+impl History {
+	/// Creates a new, empty, [`History`] which can use up to `budget` bytes to store edits.
+	#[inline(always)] pub fn with_budget(budget:usize) -> Self { Self {budget, ..Self::default()} }
+	
+	/// The number of bytes this history can use to store edits.
+	#[inline(always)] pub const fn budget(&self) -> usize { self.budget }
+	
+	/// The number of bytes this history is using to store edits, including the edit in progress (if any).
+	#[inline(always)] pub const fn used(&self) -> usize { self.used + self.pending_used }
+	
+	/// The number of edits which can be undone.
+	#[inline(always)] pub fn undo_len(&self) -> usize { self.undo.len() }
+	
+	/// The number of edits which can be redone.
+	#[inline(always)] pub fn redo_len(&self) -> usize { self.redo.len() }
+	
+	/// Forgets every stored edit.
+	pub fn clear(&mut self) {
+		self.undo.clear();
+		self.redo.clear();
+		self.used = 0;
+	}
+	
+	/// Whether edits are being recorded.
+	#[inline(always)] pub(crate) fn is_recording(&self) -> bool { self.budget > 0 && self.depth > 0 && !self.overflowed }
+	
+	/// Whether finishing the edit in progress (if any) finishes the outermost edit.
+	#[inline(always)] pub(crate) fn is_outermost(&self) -> bool { self.depth <= 1 }
+	
+	/// Starts an edit, or a nested part of the edit in progress.
+	#[inline(always)] pub(crate) fn begin(&mut self) { self.depth += 1; }
+	
+	/// Remembers the contents of a chunk before the edit in progress touches it,
+	///  unless they have already been remembered.
+	pub(crate) fn record(&mut self, key:(i64, i64, i64), chunk:Option<&Chunk>) {
+		if !self.is_recording() || self.pending.contains_key(&key) { return; }
+		
+		let groups = chunk.map(|chunk| chunk.compress().to_vec());
+		self.pending_used += size_of::<ChunkDelta>() + groups.as_ref().map_or(0, |groups| groups.len()*size_of::<BlockGroup>());
+		self.pending.insert(key, groups);
+		
+		while self.used + self.pending_used > self.budget {
+			if let Some(old) = self.undo.pop_front() { self.used -= old.size(); continue; }
+			
+			self.overflowed = true;
+			self.pending.clear();
+			self.pending_used = 0;
+			break;
+		}
+	}
+	
+	/// Finishes an edit (or a nested part of one), storing the difference it made once the outermost edit is finished,
+	///  alongside the contents of the chests it removed.
+	pub(crate) fn end(&mut self, chunks:&HashMap<(i64, i64, i64), Chunk>, chests:Vec<((i64, i64, i64, i64), Inventory)>) {
+		self.depth = self.depth.saturating_sub(1);
+		if self.depth > 0 { return; }
+		if self.overflowed {
+			self.overflowed = false;
+			self.clear();
+			return;
+		}
+		if self.pending.is_empty() { return; }
+		
+		let air     = Chunk::filled_with(0);
+		let changes = self.pending.drain().filter_map(|(key, before)| {
+			let after   = chunks.get(&key)?;
+			let created = before.is_none();
+			let delta   = before.map_or_else(|| air.clone(), |groups| CompressedChunk::from(groups).decompressed()).delta(after);
+			// Chunks which were created are kept even if they are empty, so that undoing the edit removes them.
+			(created || delta.len() > 1 || delta.first().is_some_and(|group| group.block_id != 0)).then_some(ChunkDelta {key, created, delta})
+		}).collect::<Vec<_>>();
+		self.pending_used = 0;
+		if changes.is_empty() { return; }
+		
+		self.redo.clear();
+		self.push_undo(Edit {chunks: changes, chests});
+	}
+	
+	/// Takes the most recent edit, to be undone.
+	#[inline(always)] pub(crate) fn pop_undo(&mut self) -> Option<Edit> {
+		let edit = self.undo.pop_back()?;
+		self.used -= edit.size();
+		Some(edit)
+	}
+	
+	/// Takes the most recently undone edit, to be redone.
+	#[inline(always)] pub(crate) fn pop_redo(&mut self) -> Option<Edit> {
+		let edit = self.redo.pop()?;
+		self.used -= edit.size();
+		Some(edit)
+	}
+	
+	/// Stores an edit which can be undone, forgetting the oldest edits if the budget is exceeded.
+	pub(crate) fn push_undo(&mut self, edit:Edit) {
+		self.used += edit.size();
+		self.undo.push_back(edit);
+		while self.used > self.budget {
+			match self.undo.pop_front() {
+				Some(old) => self.used -= old.size(),
+				None      => { self.redo.clear(); self.used = 0; }
+			}
+		}
+	}
+	
+	/// Stores an edit which has been undone, so that it can be redone.
+	pub(crate) fn push_redo(&mut self, edit:Edit) {
+		self.used += edit.size();
+		self.redo.push(edit);
+	}
+}
+
+
+
+
+
+/// The changes a single edit made to every chunk it touched, and to the world's chests.
+#[derive(Clone, Debug)]
+pub(crate) struct Edit {
+	pub(crate) chunks:Vec<ChunkDelta>,
+	/// The contents of every chest which undoing (or, once undone, redoing) this edit brings back.
+	pub(crate) chests:Vec<((i64, i64, i64, i64), Inventory)>
+}
+
+impl Edit {
+	/// The (approximate) number of bytes used to store this edit.
+	fn size(&self) -> usize {
+		let chunks = self.chunks.iter().map(|chunk| size_of::<ChunkDelta>() + chunk.delta.len()*size_of::<BlockGroup>()).sum::<usize>();
+		let chests = self.chests.iter().map(|(_, contents)| size_of::<((i64, i64, i64, i64), Inventory)>() + contents.len()*size_of::<Option<ItemStack>>()).sum::<usize>();
+		chunks + chests
+	}
+}
+
+
+
+/// The change made to a single chunk, as the exclusive-or of its contents before and after,
+///  which (when applied again) turns either into the other.
+#[derive(Clone, Debug)]
+pub(crate) struct ChunkDelta {
+	pub(crate) key:(i64, i64, i64),
+	/// Whether the chunk did not exist before the edit.
+	pub(crate) created:bool,
+	pub(crate) delta:Vec<BlockGroup>
+}
\ No newline at end of file
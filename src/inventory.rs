@@ -0,0 +1,201 @@
+use crate::collectable::AnyCollectable;
+
+#[cfg(feature="serde")]
+use serde::{Deserializer, Deserialize};
+#[cfg(feature="serde")]
+use serde_derive::{Deserialize, Serialize};
+
+use core::convert::Into;
+use core::iter::Iterator;
+use std::vec::Vec;
+
+
+
+
+
+/// A stack of identical collectables, occupying a single inventory slot.
+/// 
+/// A stack knows the index of its slot, which an [`Inventory`] keeps up to date as stacks are put in or moved around it;
+///  stacks outside of an inventory (such as those of a [`Recipe`](crate::recipes::Recipe)) are in slot `0`.
+#[derive(PartialEq, Clone, Debug, Copy, Hash, Eq)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+pub struct ItemStack {
+	/// The index of the slot the stack is in.
+	#[cfg_attr(feature="serde", serde(default))]
+	slot:usize,
+	/// What the stack is made of.
+	pub collectable:AnyCollectable,
+	/// How many collectables are in the stack.
+	pub count:u32
+}
+
+impl ItemStack {
+	/// Creates a new [`ItemStack`] of a number of (a block or item).
+	#[inline(always)] pub fn new<C:Into<AnyCollectable>>(collectable:C, count:u32) -> Self { Self {slot: 0, collectable: collectable.into(), count} }
+	
+	/// This stack, in the specified slot (to be [placed](Inventory::place) in an inventory).
+	#[inline(always)] pub const fn at(self, slot:usize) -> Self { Self {slot, ..self} }
+	
+	/// The index of the slot this stack is in.
+	#[inline(always)] pub const fn slot(&self) -> usize { self.slot }
+	
+	/// The most collectables that fit in this stack, decided by the [type](crate::collectable::CollectableType::stack_limit) of its collectable.
+	#[inline(always)] pub fn limit(&self) -> u32 { self.collectable.typ().stack_limit() }
+	
+	/// How many more collectables fit in this stack.
+	#[inline(always)] pub fn space(&self) -> u32 { self.limit().saturating_sub(self.count) }
+	
+	/// Whether no more collectables fit in this stack.
+	#[inline(always)] pub fn is_full(&self) -> bool { self.space() == 0 }
+}
+
+
+
+/// An inventory: a number of slots, each of which can hold an [`ItemStack`].
+/// 
+/// A player's inventory follows the game's layout: a hotbar of [`Inventory::HOTBAR`] slots,
+///  followed by three rows of as many slots, for [`Inventory::PLAYER_SLOTS`] slots in all.
+#[derive(PartialEq, Clone, Debug, Hash, Eq)]
+#[cfg_attr(feature="serde", derive(Serialize))]
+pub struct Inventory {
+	slots:Vec<Option<ItemStack>>
+}
+
+impl Inventory {
+	/// The number of slots in the hotbar, which are the first slots of a player's inventory.
+	pub const HOTBAR:usize       = 8;
+	/// The number of slots in a player's inventory, including the hotbar.
+	pub const PLAYER_SLOTS:usize = 4*Self::HOTBAR;
+	
+	/// Creates a new, empty, [`Inventory`] with the specified number of slots.
+	#[inline(always)] pub fn new(slots:usize) -> Self { Self {slots: vec![None; slots]} }
+	
+	/// Creates a new, empty, player's inventory.
+	#[inline(always)] pub fn player() -> Self { Self::new(Self::PLAYER_SLOTS) }
+	
+	/// The number of slots in this inventory.
+	#[inline(always)] pub fn len(&self) -> usize { self.slots.len() }
+	
+	/// Whether every slot of this inventory is empty.
+	#[inline(always)] pub fn is_empty(&self) -> bool { self.slots.iter().all(Option::is_none) }
+	
+	/// Gets the stack in a slot, if there is one.
+	#[inline(always)] pub fn get(&self, slot:usize) -> Option<&ItemStack> { self.slots.get(slot)?.as_ref() }
+	
+	/// Mutably gets the stack in a slot, if there is one.
+	#[inline(always)] pub fn get_mut(&mut self, slot:usize) -> Option<&mut ItemStack> { self.slots.get_mut(slot)?.as_mut() }
+	
+	/// Puts a stack (or, with [`None`], nothing) in a slot, returning the stack that was there.
+	/// 
+	/// Stacks are put in as they are, even if they are larger than their limit.  
+	/// Returns [`None`] (and does nothing) if the slot does not exist.
+	pub fn set(&mut self, slot:usize, stack:Option<ItemStack>) -> Option<ItemStack> {
+		let (idx, slot) = (slot, self.slots.get_mut(slot)?);
+		core::mem::replace(slot, stack.filter(|stack| stack.count > 0).map(|stack| stack.at(idx)))
+	}
+	
+	/// Puts a stack in [its slot](ItemStack::slot), returning the stack that was there.
+	/// 
+	/// Returns [`None`] (and does nothing) if the slot does not exist.
+	#[inline(always)] pub fn place(&mut self, stack:ItemStack) -> Option<ItemStack> { self.set(stack.slot, Some(stack)) }
+	
+	/// Returns an iterator over every stack in this inventory, alongside the index of its slot.
+	pub fn iter(&self) -> impl Iterator<Item=(usize, &ItemStack)> + '_ {
+		self.slots.iter().enumerate().filter_map(|(slot, stack)| Some((slot, stack.as_ref()?)))
+	}
+	
+	/// The total number of a collectable in this inventory.
+	pub fn count<C:Into<AnyCollectable>>(&self, collectable:C) -> u32 {
+		let collectable = collectable.into();
+		self.iter().filter(|(_, stack)| stack.collectable == collectable).map(|(_, stack)| stack.count).sum()
+	}
+	
+	/// Adds a stack to this inventory, returning whatever did not fit.
+	/// 
+	/// Stacks of the same collectable which are not full are topped up first, then empty slots are filled, in slot order.
+	pub fn add(&mut self, mut stack:ItemStack) -> Option<ItemStack> {
+		for existing in self.slots.iter_mut().flatten().filter(|existing| existing.collectable == stack.collectable) {
+			let n = existing.space().min(stack.count);
+			existing.count += n;
+			stack.count    -= n;
+		}
+		for (idx, slot) in self.slots.iter_mut().enumerate().filter(|(_, slot)| slot.is_none()) {
+			if stack.count == 0 { break; }
+			
+			let n = stack.limit().min(stack.count);
+			*slot = Some(ItemStack {slot: idx, count: n, ..stack});
+			stack.count -= n;
+		}
+		(stack.count > 0).then_some(stack)
+	}
+	
+	/// Removes up to `count` of a collectable from this inventory, returning how many were removed.
+	/// 
+	/// Collectables are taken from the last slots first, so that the hotbar is emptied last.
+	pub fn remove<C:Into<AnyCollectable>>(&mut self, collectable:C, mut count:u32) -> u32 {
+		let (collectable, wanted) = (collectable.into(), count);
+		for slot in self.slots.iter_mut().rev() {
+			let Some(stack) = slot.as_mut().filter(|stack| stack.collectable == collectable) else { continue; };
+			
+			let n = stack.count.min(count);
+			stack.count -= n;
+			count       -= n;
+			if stack.count == 0 { *slot = None; }
+			if count == 0 { break; }
+		}
+		wanted - count
+	}
+	
+	/// Combines stacks of the same collectable, filling earlier stacks from later ones.
+	pub fn merge(&mut self) {
+		for i in 0..self.slots.len() {
+			for j in i+1..self.slots.len() {
+				let (head, tail) = self.slots.split_at_mut(j);
+				let (Some(into), Some(from)) = (&mut head[i], &mut tail[0]) else { continue; };
+				if into.collectable != from.collectable { continue; }
+				
+				let n = into.space().min(from.count);
+				into.count += n;
+				from.count -= n;
+				if from.count == 0 { tail[0] = None; }
+			}
+		}
+	}
+	
+	/// [Merges](Inventory::merge) every stack in the slots from `from` onwards, then sorts them:
+	///  blocks (by ID) before items (by variant), with larger stacks first and empty slots last.
+	/// 
+	/// Passing [`Inventory::HOTBAR`] leaves a player's hotbar as it is.
+	pub fn sort(&mut self, from:usize) {
+		let Some(slots) = self.slots.get_mut(from..) else { return; };
+		
+		let mut sorted = Self {slots: slots.to_vec()};
+		sorted.merge();
+		sorted.slots.sort_by_key(|stack| match stack {
+			Some(ItemStack {collectable: AnyCollectable::Block(block), count, ..}) => (0, *block as u8, u32::MAX - count),
+			Some(ItemStack {collectable: AnyCollectable::Item(item), count, ..})   => (1, *item as u8, u32::MAX - count),
+			None                                                                   => (2, 0, 0)
+		});
+		for (idx, (slot, stack)) in slots.iter_mut().zip(sorted.slots).enumerate() { *slot = stack.map(|stack| stack.at(from+idx)); }
+	}
+	
+	/// Empties every slot of this inventory.
+	#[inline(always)] pub fn clear(&mut self) { self.slots.fill(None); }
+
+}
+
+/// Deserializes an inventory, putting every stack in the slot it was found in (whichever slot it claims to be in).
+#[cfg(feature="serde")]
+impl<'de> Deserialize<'de> for Inventory {
+	fn deserialize<D:Deserializer<'de>>(d:D) -> Result<Self, D::Error> {
+		#[derive(Deserialize)]
+		struct InventoryData {
+			slots:Vec<Option<ItemStack>>
+		}
+		
+		let slots = InventoryData::deserialize(d)?.slots;
+		let mut inventory = Self::new(slots.len());
+		for (slot, stack) in slots.into_iter().enumerate() { inventory.set(slot, stack); }
+		Ok(inventory)
+	}
+}
\ No newline at end of file
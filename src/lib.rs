@@ -2,11 +2,41 @@
 
 /// Data-types and functionality for working with [`Collectable`] items.
 pub mod collectable;
+/// A registry of every [`Collectable`](crate::collectable::Collectable), with its index.
+pub mod registry;
+/// Inventories of [`Collectable`](crate::collectable::Collectable) stacks.
+pub mod inventory;
+/// Crafting recipes, and working out what they need.
+pub mod recipes;
+/// The contents of a world's chests.
+pub mod chest;
 /// Data-types and functionality for working with 4D Miner chunk-data.
 pub mod chunk;
 /// Data-types and functionality for handling [`World`]-wide data.
 pub mod world;
 /// Utilities for supported functionality.
 pub mod util;
+/// Data-types and functionality for copying regions of blocks between [`World`](crate::world::World)(crate::world::World)s.
+pub mod schematic;
+/// Rotations and reflections of 4D regions.
+pub mod transform;
+/// Deterministic hashing and noise for procedural content.
+pub mod noise;
+/// Biomes of 4D Miner worlds.
+pub mod biome;
+/// Procedural generation of terrain resembling that of 4D Miner worlds.
+pub mod worldgen;
+/// Trees and other plants which can be grown in a [`World`](crate::world::World).
+pub mod vegetation;
+/// Finding blocks, such as ores, in a [`World`](crate::world::World).
+pub mod search;
+/// Flood fills and connected components of blocks, in 4D.
+pub mod flood;
+/// Detection of caves in the underground of a [`World`](crate::world::World).
+pub mod cave;
+/// Import and export of MagicaVoxel `.vox` models.
+pub mod vox;
+/// Undo and redo history for [`World`](crate::world::World) edits.
+pub mod history;
 /// Error-types for `fdm-toolkit`.
 pub mod err;
\ No newline at end of file
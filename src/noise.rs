@@ -0,0 +1,60 @@
+/// Hashes a seed and a point in 4D space into a pseudo-random number.
+/// 
+/// The same seed and point always produce the same number.
+pub const fn hash4(seed:u64, p:(i64, i64, i64, i64)) -> u64 {
+	let mut h = seed ^ 0x9E37_79B9_7F4A_7C15;
+	h = mix(h ^ p.0 as u64);
+	h = mix(h ^ p.1 as u64);
+	h = mix(h ^ p.2 as u64);
+	mix(h ^ p.3 as u64)
+}
+
+/// Hashes a seed and a point in 4D space into a pseudo-random number in the range `[0.0, 1.0)`.
+#[inline(always)] pub const fn unit4(seed:u64, p:(i64, i64, i64, i64)) -> f64 { (hash4(seed, p) >> 11) as f64 / (1u64 << 53) as f64 }
+
+/// Smoothly-varying value noise at a point in 4D space, in the range `[0.0, 1.0)`.
+/// 
+/// The noise varies over a distance of roughly one unit, so points should be scaled down to make features larger.
+pub fn value4(seed:u64, p:(f64, f64, f64, f64)) -> f64 {
+	let base = (p.0.floor(), p.1.floor(), p.2.floor(), p.3.floor());
+	let t    = [fade(p.0-base.0), fade(p.1-base.1), fade(p.2-base.2), fade(p.3-base.3)];
+	let base = (base.0 as i64, base.1 as i64, base.2 as i64, base.3 as i64);
+	
+	let mut out = 0.0;
+	for corner in 0..16 {
+		let mut weight = 1.0;
+		let mut offset = [0; 4];
+		for (axis, o) in offset.iter_mut().enumerate() {
+			*o = (corner >> axis) & 1;
+			weight *= if *o == 1 { t[axis] } else { 1.0-t[axis] };
+		}
+		out += weight * unit4(seed, (base.0+offset[0], base.1+offset[1], base.2+offset[2], base.3+offset[3]));
+	}
+	out
+}
+
+/// Fractal (layered) value noise at a point in 4D space, in the range `[0.0, 1.0)`.
+/// 
+/// Each of the `octaves` has double the frequency, and half the influence, of the last.
+pub fn fractal4(seed:u64, p:(f64, f64, f64, f64), octaves:u32) -> f64 {
+	let (mut out, mut total, mut amplitude, mut frequency) = (0.0, 0.0, 1.0, 1.0);
+	for octave in 0..octaves.max(1) {
+		out       += amplitude * value4(seed.wrapping_add(octave as u64), (p.0*frequency, p.1*frequency, p.2*frequency, p.3*frequency));
+		total     += amplitude;
+		amplitude /= 2.0;
+		frequency *= 2.0;
+	}
+	out / total
+}
+
+
+
+
+
+#[inline(always)] const fn mix(mut h:u64) -> u64 {
+	h = (h ^ (h >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+	h = (h ^ (h >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+	h ^ (h >> 31)
+}
+
+#[inline(always)] fn fade(t:f64) -> f64 { t*t*t*(t*(t*6.0-15.0)+10.0) }
\ No newline at end of file
@@ -0,0 +1,134 @@
+use crate::collectable::AnyCollectable;
+use crate::inventory::{ItemStack, Inventory};
+
+#[cfg(feature="serde")]
+use serde_derive::{Deserialize, Serialize};
+
+use core::iter::{FromIterator, IntoIterator, Iterator, Extend};
+use std::collections::HashMap;
+use core::convert::Into;
+use std::vec::Vec;
+
+
+
+
+
+/// A crafting recipe: some collectables which are used up to make another.
+#[derive(PartialEq, Clone, Debug, Hash, Eq)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+pub struct Recipe {
+	/// The collectables (and how many of each) used up by the recipe.
+	pub inputs:Vec<ItemStack>,
+	/// The collectable (and how many of it) made by the recipe.
+	pub output:ItemStack
+}
+
+impl Recipe {
+	/// Creates a new [`Recipe`] making `count` of `output` from some inputs.
+	pub fn new<C:Into<AnyCollectable>, I:IntoIterator<Item=(AnyCollectable, u32)>>(output:C, count:u32, inputs:I) -> Self {
+		Self {
+			inputs: inputs.into_iter().map(|(collectable, count)| ItemStack::new(collectable, count)).collect(),
+			output: ItemStack::new(output, count)
+		}
+	}
+	
+	/// How many times this recipe can be crafted with the collectables in an inventory.
+	pub fn times_craftable(&self, inventory:&Inventory) -> u32 {
+		self.inputs.iter()
+			.map(|input| inventory.count(input.collectable) / input.count.max(1))
+			.min()
+			.unwrap_or(0)
+	}
+}
+
+
+
+/// A collection of crafting [`Recipe`]s.
+/// 
+/// The game's recipes are not included, so a book starts out empty: recipes can be [added](RecipeBook::add) one at a time,
+///  collected into a book (or used to extend one) from an iterator, or imported from elsewhere.  
+/// Recipe books serialize as a list of recipes, so (with the `serde` feature) a list of recipes in any format serde supports,
+///  such as JSON, can be imported as a book, and a book can be exported, edited and imported again.
+#[derive(PartialEq, Clone, Debug, Default, Eq)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature="serde", serde(transparent))]
+pub struct RecipeBook(Vec<Recipe>);
+
+impl RecipeBook {
+	/// Creates a new, empty, [`RecipeBook`].
+	#[inline(always)] pub const fn new() -> Self { Self(Vec::new()) }
+	
+	/// Adds a recipe to this book.
+	#[inline(always)] pub fn add(&mut self, recipe:Recipe) { self.0.push(recipe); }
+	
+	/// Every recipe in this book.
+	#[inline(always)] pub fn recipes(&self) -> &[Recipe] { &self.0 }
+	
+	/// Returns an iterator over every recipe which makes a collectable.
+	pub fn recipes_for<C:Into<AnyCollectable>>(&self, output:C) -> impl Iterator<Item=&Recipe> + '_ {
+		let output = output.into();
+		self.0.iter().filter(move |recipe| recipe.output.collectable == output)
+	}
+	
+	/// Returns an iterator over every recipe which uses up a collectable.
+	pub fn uses_of<C:Into<AnyCollectable>>(&self, input:C) -> impl Iterator<Item=&Recipe> + '_ {
+		let input = input.into();
+		self.0.iter().filter(move |recipe| recipe.inputs.iter().any(|stack| stack.collectable == input))
+	}
+	
+	/// Returns an iterator over every recipe which can be crafted with the collectables in an inventory,
+	///  alongside how many times it can be crafted.
+	pub fn craftable<'a>(&'a self, inventory:&'a Inventory) -> impl Iterator<Item=(&'a Recipe, u32)> + 'a {
+		self.0.iter().map(|recipe| (recipe, recipe.times_craftable(inventory))).filter(|&(_, n)| n > 0)
+	}
+	
+	/// Works out the raw materials (collectables without a recipe) needed to craft `count` of a collectable,
+	///  by expanding every input through the first recipe which makes it.
+	/// 
+	/// Surplus from crafting one input is put towards the others, so shared inputs are not over-counted.  
+	/// The materials are returned in the order they are first needed, and any count too large for a `u32` is capped at [`u32::MAX`].
+	pub fn raw_materials<C:Into<AnyCollectable>>(&self, target:C, count:u32) -> Vec<(AnyCollectable, u32)> {
+		let mut raw     = Vec::new();
+		let mut surplus = HashMap::new();
+		self.expand(target.into(), count, &mut raw, &mut surplus, &mut Vec::new());
+		raw
+	}
+	
+	/// Adds the raw materials needed to craft `count` of a collectable to `raw`.
+	/// 
+	/// Collectables which are already being expanded (in `path`) are treated as raw materials, to break cycles.
+	fn expand(&self, target:AnyCollectable, count:u32, raw:&mut Vec<(AnyCollectable, u32)>, surplus:&mut HashMap<AnyCollectable, u32>, path:&mut Vec<AnyCollectable>) {
+		let spare = surplus.entry(target).or_insert(0);
+		let used  = (*spare).min(count);
+		*spare -= used;
+		let count = count - used;
+		if count == 0 { return; }
+		
+		let recipe = self.recipes_for(target).next().filter(|recipe| recipe.output.count > 0 && !path.contains(&target));
+		let Some(recipe) = recipe else {
+			match raw.iter_mut().find(|(collectable, _)| *collectable == target) {
+				Some((_, n)) => *n = n.saturating_add(count),
+				None         => raw.push((target, count))
+			}
+			return;
+		};
+		
+		let batches = count.div_ceil(recipe.output.count);
+		let spare   = surplus.entry(target).or_insert(0);
+		*spare = spare.saturating_add(batches.saturating_mul(recipe.output.count) - count);
+		
+		path.push(target);
+		for input in &recipe.inputs { self.expand(input.collectable, input.count.saturating_mul(batches), raw, surplus, path); }
+		path.pop();
+	}
+}
+
+impl FromIterator<Recipe> for RecipeBook {
+	/// Collects recipes into a [`RecipeBook`], keeping their order.
+	fn from_iter<I:IntoIterator<Item=Recipe>>(iter:I) -> Self { Self(iter.into_iter().collect()) }
+}
+
+impl Extend<Recipe> for RecipeBook {
+	/// Adds recipes to the end of this book, keeping their order.
+	fn extend<I:IntoIterator<Item=Recipe>>(&mut self, iter:I) { self.0.extend(iter); }
+}
\ No newline at end of file
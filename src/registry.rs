@@ -0,0 +1,81 @@
+use crate::collectable::{AnyCollectable, CollectableIndex, CollectableType, Item};
+use crate::world::Block;
+
+use std::collections::HashMap;
+use core::convert::Into;
+use core::default::Default;
+use core::iter::Iterator;
+use std::vec::Vec;
+use core::slice;
+
+
+
+
+
+/// Everything known about a single collectable.
+#[derive(PartialEq, Clone, Debug, Copy, Hash, Eq)]
+pub struct RegistryEntry {
+	/// The collectable's index.
+	pub index:CollectableIndex,
+	/// The collectable itself.
+	pub collectable:AnyCollectable,
+	/// The collectable's display name.
+	pub name:&'static str,
+	/// The type of the collectable.
+	pub typ:CollectableType
+}
+
+
+
+/// A registry of every [`Block`] and [`Item`], looked up by their [`CollectableIndex`] or display name.
+/// 
+/// Blocks (in block ID order) come first, followed by items.
+#[derive(Clone, Debug)]
+pub struct Registry {
+	entries:Vec<RegistryEntry>,
+	by_index:HashMap<CollectableIndex, usize>
+}
+
+impl Registry {
+	/// Creates a new [`Registry`] of every block (other than air) and item.
+	pub fn new() -> Self {
+		let blocks = Block::iter().filter(|block| *block != Block::Air).map(AnyCollectable::Block);
+		let items  = Item::iter().map(AnyCollectable::Item);
+		
+		let entries = blocks.chain(items).map(|collectable| RegistryEntry {
+			index: collectable.into(),
+			collectable,
+			name: collectable.as_str(),
+			typ: collectable.typ()
+		}).collect::<Vec<_>>();
+		let by_index = entries.iter().enumerate().map(|(idx, entry)| (entry.index, idx)).collect();
+		
+		Self {entries, by_index}
+	}
+	
+	/// The number of collectables in this registry.
+	#[inline(always)] pub fn len(&self) -> usize { self.entries.len() }
+	
+	/// Whether this registry has no collectables.
+	#[inline(always)] pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+	
+	/// Returns an iterator over every collectable in this registry, with blocks first.
+	#[inline(always)] pub fn iter(&self) -> slice::Iter<'_, RegistryEntry> { self.entries.iter() }
+	
+	/// Looks up a collectable by its index (or anything which can be turned into one).
+	pub fn get<I:Into<CollectableIndex>>(&self, index:I) -> Option<&RegistryEntry> { self.entries.get(*self.by_index.get(&index.into())?) }
+	
+	/// Looks up a collectable by its display name, ignoring case.
+	pub fn by_name(&self, name:&str) -> Option<&RegistryEntry> { self.entries.iter().find(|entry| entry.name.eq_ignore_ascii_case(name)) }
+}
+
+impl Default for Registry {
+	#[inline(always)] fn default() -> Self { Self::new() }
+}
+
+impl<'a> IntoIterator for &'a Registry {
+	type IntoIter = slice::Iter<'a, RegistryEntry>;
+	type Item     = &'a RegistryEntry;
+	
+	fn into_iter(self) -> Self::IntoIter { self.entries.iter() }
+}
\ No newline at end of file
@@ -0,0 +1,193 @@
+use crate::err::SchematicReadError;
+use crate::chunk::BlockGroup;
+use crate::transform::Transform;
+use crate::util::WorldRect4;
+
+#[cfg(feature="serde")]
+use serde::{
+	de::{SeqAccess, Visitor, Error as DeserializationError},
+	Deserializer, Deserialize, Serializer, Serialize
+};
+
+#[cfg(feature="serde")]
+use core::fmt::{Formatter, Result as FmtResult};
+use core::convert::TryInto;
+use core::iter::Iterator;
+use std::vec::Vec;
+
+
+
+
+
+/// A copy of a (4D) rectangular region of blocks, which can be pasted into a [`World`](crate::world::World).
+/// 
+/// Blocks are stored run-length encoded as [`BlockGroup`]s,
+///  in the same X, Y, Z, W order as chunk-data.
+/// 
+/// # File format
+/// All integers are little-endian.
+/// 
+/// | Offset | Size   | Field                                                 |
+/// |--------|--------|-------------------------------------------------------|
+/// | 0      | 4      | The magic number, `FDMS`.                             |
+/// | 4      | 1      | The version of the format (currently `1`).            |
+/// | 5      | 16     | The size along the X, Y, Z and W axes (4 * `u32`).    |
+/// | 21     | 4      | The number of block groups, `n` (`u32`).              |
+/// | 25     | 2 * `n`| The block groups, as (block ID, span) pairs.          |
+#[derive(PartialEq, Clone, Debug, Hash, Eq)]
+pub struct Schematic {
+	size:(usize, usize, usize, usize),
+	groups:Vec<BlockGroup>
+}
+
+impl Schematic {
+	/// The magic number that schematic files start with.
+	pub const MAGIC:[u8; 4] = *b"FDMS";
+	/// The version of the file format that is written.
+	pub const VERSION:u8 = 1;
+	
+	/// Creates a new [`Schematic`] of the given size from a sequence of block IDs,
+	///  in X, Y, Z, W order.
+	/// 
+	/// Missing blocks are filled with air, and excess blocks are ignored.
+	pub fn new<I:IntoIterator<Item=u8>>(size:(usize, usize, usize, usize), blocks:I) -> Self {
+		let volume = size.0.saturating_mul(size.1).saturating_mul(size.2).saturating_mul(size.3);
+		Self {
+			size,
+			groups: BlockGroup::encode(blocks.into_iter().chain(core::iter::repeat(0)).take(volume))
+		}
+	}
+	
+	/// The size of this schematic along the X, Y, Z and W axes.
+	#[inline(always)] pub const fn size(&self) -> (usize, usize, usize, usize) { self.size }
+	
+	/// The number of blocks in this schematic, saturating at [`usize::MAX`].
+	#[inline(always)] pub const fn volume(&self) -> usize { self.size.0.saturating_mul(self.size.1).saturating_mul(self.size.2).saturating_mul(self.size.3) }
+	
+	/// The run-length encoded blocks in this schematic.
+	#[inline(always)] pub fn groups(&self) -> &[BlockGroup] { &self.groups }
+	
+	/// Returns an iterator over the ID of every block in this schematic, in X, Y, Z, W order.
+	pub fn blocks(&self) -> impl Iterator<Item=u8> + '_ {
+		self.groups.iter().flat_map(|group| core::iter::repeat_n(group.block_id, group.span as usize))
+	}
+	
+	/// Returns an iterator over every block in this schematic, alongside its location within the schematic.
+	pub fn iter(&self) -> impl Iterator<Item=((usize, usize, usize, usize), u8)> + '_ {
+		self.blocks().enumerate().map(|(pos, id)| (self.location_of(pos), id))
+	}
+	
+	/// (Try to) Get the ID of a block at a given location within this schematic.
+	pub fn get_block(&self, loc:(usize, usize, usize, usize)) -> Option<u8> {
+		if loc.0 >= self.size.0 || loc.1 >= self.size.1 || loc.2 >= self.size.2 || loc.3 >= self.size.3 { return None; }
+		
+		let mut pos = ((loc.0*self.size.1 + loc.1)*self.size.2 + loc.2)*self.size.3 + loc.3;
+		for group in &self.groups {
+			if pos < group.span as usize { return Some(group.block_id); }
+			pos -= group.span as usize;
+		}
+		None
+	}
+	
+	/// Creates a copy of this schematic which has been rotated and/or reflected.
+	pub fn transformed(&self, transform:Transform) -> Self {
+		let size = transform.apply_size(self.size);
+		
+		let mut blocks = vec![0; self.volume()];
+		for (loc, block_id) in self.iter() {
+			let (x, y, z, w) = transform.apply(loc, self.size);
+			blocks[((x*size.1 + y)*size.2 + z)*size.3 + w] = block_id;
+		}
+		Self::new(size, blocks)
+	}
+	
+	/// The area of the world this schematic covers when pasted with its first corner at `origin`.
+	#[inline(always)] pub const fn bounds(&self, origin:(i64, i64, i64, i64)) -> WorldRect4 { WorldRect4::with_size(origin, self.size) }
+	
+	/// Converts an index into this schematic's blocks into a location within the schematic.
+	#[inline(always)] pub(crate) const fn location_of(&self, pos:usize) -> (usize, usize, usize, usize) {
+		(
+			pos / (self.size.3*self.size.2*self.size.1),
+			(pos / (self.size.3*self.size.2)) % self.size.1,
+			(pos / self.size.3) % self.size.2,
+			pos % self.size.3
+		)
+	}
+	
+	/// Deserializes a schematic from the bytes of a schematic file.
+	pub fn from_bytes(bytes:&[u8]) -> Result<Self, SchematicReadError> {
+		#[inline(always)] fn read_u32(bytes:&[u8], at:usize) -> Result<usize, SchematicReadError> {
+			Ok(u32::from_le_bytes(bytes.get(at..at+4).ok_or(SchematicReadError::Truncated(at))?.try_into().unwrap()) as usize)
+		}
+		
+		if bytes.get(0..4) != Some(&Self::MAGIC) { return Err(SchematicReadError::InvalidMagic); }
+		match bytes.get(4) {
+			Some(&Self::VERSION) => (),
+			Some(&v)             => return Err(SchematicReadError::UnsupportedVersion(v)),
+			None                 => return Err(SchematicReadError::Truncated(4))
+		}
+		
+		let size   = (read_u32(bytes, 5)?, read_u32(bytes, 9)?, read_u32(bytes, 13)?, read_u32(bytes, 17)?);
+		let volume = size.0.checked_mul(size.1).and_then(|v| v.checked_mul(size.2)).and_then(|v| v.checked_mul(size.3))
+			.ok_or(SchematicReadError::TooLarge(size))?;
+		let n      = read_u32(bytes, 21)?;
+		let end    = n.checked_mul(2).and_then(|len| len.checked_add(25)).ok_or(SchematicReadError::Truncated(25))?;
+		
+		let groups = bytes.get(25..end).ok_or(SchematicReadError::Truncated(25))?
+			.chunks_exact(2)
+			.map(|pair| BlockGroup {block_id: pair[0], span: pair[1]})
+			.collect::<Vec<_>>();
+		if bytes.len() > end { return Err(SchematicReadError::TrailingData(end)); }
+		
+		let found = groups.iter().map(|group| group.span as usize).sum();
+		if found != volume { return Err(SchematicReadError::SizeMismatch {expected: volume, found}); }
+		
+		Ok(Self {size, groups})
+	}
+	
+	/// Serializes this schematic into the bytes of a schematic file.
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut out = Vec::with_capacity(25+self.groups.len()*2);
+		out.extend_from_slice(&Self::MAGIC);
+		out.push(Self::VERSION);
+		for n in [self.size.0, self.size.1, self.size.2, self.size.3, self.groups.len()] {
+			out.extend_from_slice(&(n as u32).to_le_bytes());
+		}
+		for &group in &self.groups { out.extend_from_slice(&<[u8; 2]>::from(group)); }
+		out
+	}
+}
+
+#[cfg(feature="serde")]
+impl Serialize for Schematic {
+	/// Serializes this schematic as the bytes of a schematic file.
+	fn serialize<S:Serializer>(&self, s:S) -> Result<S::Ok, S::Error> { s.serialize_bytes(&self.to_bytes()) }
+}
+
+#[cfg(feature="serde")]
+impl<'de> Deserialize<'de> for Schematic {
+	fn deserialize<D:Deserializer<'de>>(d:D) -> Result<Self, D::Error> { d.deserialize_bytes(SchematicVisitor) }
+}
+
+
+
+
+
+#[cfg(feature="serde")]
+#[doc(hidden)]
+struct SchematicVisitor;
+
+#[cfg(feature="serde")]
+impl<'de> Visitor<'de> for SchematicVisitor {
+	type Value = Schematic;
+	
+	fn expecting(&self, f:&mut Formatter) -> FmtResult { f.write_str("the bytes of a schematic file") }
+	
+	fn visit_bytes<E:DeserializationError>(self, v:&[u8]) -> Result<Self::Value, E> { Schematic::from_bytes(v).map_err(E::custom) }
+	
+	fn visit_seq<A:SeqAccess<'de>>(self, mut seq:A) -> Result<Self::Value, A::Error> {
+		let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+		while let Some(b) = seq.next_element::<u8>()? { bytes.push(b); }
+		self.visit_bytes(&bytes)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use crate::flood::{Adjacency, flood};
+
+use std::collections::{HashMap, HashSet};
+use core::iter::Iterator;
+use std::vec::Vec;
+
+
+
+
+
+/// The (squared) straight-line distance between two locations in world-space.
+/// 
+/// Squared distances compare the same way as distances, without needing a square root.  
+/// Distances too large to square saturate at [`i64::MAX`].
+pub const fn distance_squared(a:(i64, i64, i64, i64), b:(i64, i64, i64, i64)) -> i64 {
+	#[inline(always)] const fn sq(a:i64, b:i64) -> u128 { let d = a.abs_diff(b) as u128; d.saturating_mul(d) }
+	
+	let sum = sq(a.0, b.0).saturating_add(sq(a.1, b.1)).saturating_add(sq(a.2, b.2)).saturating_add(sq(a.3, b.3));
+	if sum > i64::MAX as u128 { i64::MAX } else { sum as i64 }
+}
+
+/// Clusters locations in world-space into veins of locations which touch each other face-to-face.
+/// 
+/// Veins are ordered by where their first location was found in `locations`,
+///  and the locations in a vein keep their relative order from `locations`,
+///  so clustering locations sorted by distance gives the nearest vein first.
+pub fn veins(locations:&[(i64, i64, i64, i64)]) -> Vec<Vec<(i64, i64, i64, i64)>> {
+	let index = locations.iter().copied().enumerate().map(|(idx, loc)| (loc, idx)).collect::<HashMap<_, _>>();
+	let mut seen  = HashSet::with_capacity(locations.len());
+	let mut veins = Vec::new();
+	for &start in locations {
+		let mut vein = flood(start, Adjacency::Faces, |loc| index.contains_key(&loc), &mut seen);
+		if vein.is_empty() { continue; }
+		
+		vein.sort_by_key(|loc| index[loc]);
+		veins.push(vein);
+	}
+	veins
+}
\ No newline at end of file
@@ -0,0 +1,153 @@
+use crate::util::{WorldRect4, Rect4};
+
+use core::default::Default;
+
+
+
+
+
+/// An axis of 4D space.
+#[derive(PartialEq, Clone, Debug, Copy, Hash, Eq)]
+#[repr(u8)]
+pub enum Axis {
+	X,
+	Y,
+	Z,
+	W
+}
+
+
+
+/// A plane of rotation in 4D space.
+/// 
+/// Rotations in [`Plane::XZ`], [`Plane::XW`] and [`Plane::ZW`] keep "up" (the Y axis) intact.
+#[derive(PartialEq, Clone, Debug, Copy, Hash, Eq)]
+pub enum Plane {
+	XY,
+	XZ,
+	XW,
+	YZ,
+	YW,
+	ZW
+}
+
+impl Plane {
+	/// The two axes that span this plane.
+	/// 
+	/// A positive quarter-turn in this plane rotates the first axis onto the second.
+	pub const fn axes(&self) -> (Axis, Axis) {
+		match self {
+			Self::XY => (Axis::X, Axis::Y),
+			Self::XZ => (Axis::X, Axis::Z),
+			Self::XW => (Axis::X, Axis::W),
+			Self::YZ => (Axis::Y, Axis::Z),
+			Self::YW => (Axis::Y, Axis::W),
+			Self::ZW => (Axis::Z, Axis::W)
+		}
+	}
+}
+
+
+
+/// A combination of quarter-turn rotations and reflections of a (4D) rectangular region.
+/// 
+/// Transformations are anchored to the region they are applied to,
+///  so the transformed region starts at the same corner as the original.
+#[derive(PartialEq, Clone, Debug, Copy, Hash, Eq)]
+pub struct Transform {
+	/// Which axis of the original region each axis of the transformed region is taken from.
+	source:[u8; 4],
+	/// Whether each axis of the transformed region runs backwards along its source axis.
+	flip:[bool; 4]
+}
+
+impl Transform {
+	/// The transformation which leaves everything as it is.
+	pub const IDENTITY:Self = Self {source: [0, 1, 2, 3], flip: [false; 4]};
+	
+	/// A rotation by a number of quarter-turns in a plane.
+	pub const fn rotation(plane:Plane, quarter_turns:i32) -> Self {
+		let (a, b) = plane.axes();
+		let (a, b) = (a as usize, b as usize);
+		
+		let mut turn = Self::IDENTITY;
+		turn.source[a] = b as u8;
+		turn.flip[a]   = true;
+		turn.source[b] = a as u8;
+		
+		let mut out = Self::IDENTITY;
+		let mut n   = quarter_turns.rem_euclid(4);
+		while n > 0 {
+			out = out.then(turn);
+			n -= 1;
+		}
+		out
+	}
+	
+	/// A reflection along an axis.
+	pub const fn mirror(axis:Axis) -> Self {
+		let mut out = Self::IDENTITY;
+		out.flip[axis as usize] = true;
+		out
+	}
+	
+	/// Creates the transformation which applies this transformation, then `other`.
+	pub const fn then(self, other:Self) -> Self {
+		let mut out = Self::IDENTITY;
+		let mut i   = 0;
+		while i < 4 {
+			let s = other.source[i] as usize;
+			out.source[i] = self.source[s];
+			out.flip[i]   = other.flip[i] ^ self.flip[s];
+			i += 1;
+		}
+		out
+	}
+	
+	/// Creates the transformation which undoes this transformation.
+	pub const fn inverse(self) -> Self {
+		let mut out = Self::IDENTITY;
+		let mut i   = 0;
+		while i < 4 {
+			let s = self.source[i] as usize;
+			out.source[s] = i as u8;
+			out.flip[s]   = self.flip[i];
+			i += 1;
+		}
+		out
+	}
+	
+	/// Whether this transformation moves the Y axis onto another axis.
+	#[inline(always)] pub const fn tilts_y(&self) -> bool { self.source[1] != 1 }
+	
+	/// The size of a region once it has been transformed.
+	pub const fn apply_size(&self, size:(usize, usize, usize, usize)) -> (usize, usize, usize, usize) {
+		let size = [size.0, size.1, size.2, size.3];
+		(size[self.source[0] as usize], size[self.source[1] as usize], size[self.source[2] as usize], size[self.source[3] as usize])
+	}
+	
+	/// Where a location within a region of the given size ends up once the region has been transformed.
+	pub const fn apply(&self, loc:(usize, usize, usize, usize), size:(usize, usize, usize, usize)) -> (usize, usize, usize, usize) {
+		let loc  = [loc.0, loc.1, loc.2, loc.3];
+		let size = [size.0, size.1, size.2, size.3];
+		
+		let mut out = [0; 4];
+		let mut i   = 0;
+		while i < 4 {
+			let s = self.source[i] as usize;
+			out[i] = if self.flip[i] { size[s]-1-loc[s] } else { loc[s] };
+			i += 1;
+		}
+		(out[0], out[1], out[2], out[3])
+	}
+	
+	/// The area covered by a [`Rect4`] once it has been transformed (about its lowest corner).
+	pub const fn apply_rect(&self, rect:Rect4) -> Rect4 { Rect4::with_size(rect.normalized().start, self.apply_size(rect.size())) }
+	
+	/// The area covered by a [`WorldRect4`] once it has been transformed (about its lowest corner).
+	pub const fn apply_world_rect(&self, rect:WorldRect4) -> WorldRect4 { WorldRect4::with_size(rect.normalized().start, self.apply_size(rect.size())) }
+}
+
+impl Default for Transform {
+	#[inline(always)] fn default() -> Self { Self::IDENTITY }
+}
\ No newline at end of file
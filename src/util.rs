@@ -1,8 +1,14 @@
-use crate::chunk::{CompressedChunk, BlockGroup};
+use crate::chunk::{CompressedChunk, BlockGroup, Chunk};
+use crate::transform::{Plane, Axis};
+use crate::noise::{fractal4, hash4, unit4};
+use crate::world::World;
+
+#[cfg(feature="serde")]
+use serde_derive::{Deserialize, Serialize};
 
 use core::marker::PhantomData;
-use core::iter::Iterator;
-use core::convert::Into;
+use core::iter::{IntoIterator, Iterator};
+use core::convert::{From, Into};
 use core::ops::FnMut;
 use std::boxed::Box;
 use std::sync::Arc;
@@ -41,34 +47,45 @@ impl<'a> Iterator for CompressedChunkBytesIter<'a> {
 
 
 
+/// A boxed function which decides the ID of the block placed at a given location (and its position in world-space),
+///  or that the block there should be left as it is.
+pub(crate) type Determiner = Box<dyn FnMut((usize, usize, usize, usize), (i64, i64, i64, i64))->Option<u8>>;
+
 /// Parameters that can be supplied to someone filling an area with blocks.
+/// 
+/// Seeded patterns are decided by the world-space position of each block, so that they line up seamlessly
+///  between chunks and between separate fills.
+/// When filling a lone [`Chunk`], positions within the chunk are used instead.
 pub struct FillParams<'a> {
-	pub(crate) determiner:Box<dyn FnMut((usize, usize, usize, usize))->u8>,
+	pub(crate) determiner:Determiner,
 	pub(crate) rect:Rect4,
 	           _lt:PhantomData<&'a ()>
 }
 
 impl<'a> FillParams<'a> {
-	#[inline(always)] pub fn with_determiner<F:FnMut((usize, usize, usize, usize))->u8+'static>(determiner:F, rect:Rect4) -> Self {
+	#[inline(always)] pub fn with_determiner<F:FnMut((usize, usize, usize, usize))->u8+'static>(mut determiner:F, rect:Rect4) -> Self {
 		Self {
-			determiner: Box::new(determiner),
+			determiner: Box::new(move |loc, _| Some(determiner(loc))),
 			rect,
 			_lt: PhantomData
 		}
 	}
 	
+	/// Creates [`FillParams`] whose determiner may leave a block as it is, by returning [`None`].
+	#[inline(always)] pub fn with_partial_determiner<F:FnMut((usize, usize, usize, usize))->Option<u8>+'static>(mut determiner:F, rect:Rect4) -> Self {
+		Self {
+			determiner: Box::new(move |loc, _| determiner(loc)),
+			rect,
+			_lt: PhantomData
+		}
+	}
+	
+	/// Cycles through the specified block IDs diagonally,
+	///  choosing the block for each location from the sum of its coordinates in world-space.
 	pub fn dither(block_ids:&'a [u8], rect:Rect4) -> Self {
-		let     block_ids = Arc::<[u8]>::from(block_ids);
-		let mut n = 0;
+		let block_ids = Arc::<[u8]>::from(block_ids);
 		Self {
-			determiner: Box::new(move |_| {
-				n += 1;
-				
-				let block = block_ids[n % block_ids.len()];
-				n %= usize::MAX;
-				
-				block
-			}),
+			determiner: Box::new(move |_, p| Some(block_ids[(p.0+p.1+p.2+p.3).rem_euclid(block_ids.len() as i64) as usize])),
 			rect,
 			_lt: PhantomData
 		}
@@ -76,7 +93,7 @@ impl<'a> FillParams<'a> {
 	
 	#[inline(always)] pub fn hollow(block_id:u8, rect:Rect4) -> Self {
 		Self {
-			determiner: Box::new(move |loc| if rect.has_on_perimeter(loc) { block_id } else { 0 }),
+			determiner: Box::new(move |loc, _| Some(if rect.has_on_perimeter(loc) { block_id } else { 0 })),
 			rect,
 			_lt: PhantomData
 		}
@@ -84,7 +101,204 @@ impl<'a> FillParams<'a> {
 	
 	#[inline(always)] pub fn solid(block_id:u8, rect:Rect4) -> Self {
 		Self {
-			determiner: Box::new(move |_| block_id),
+			determiner: Box::new(move |_, _| Some(block_id)),
+			rect,
+			_lt: PhantomData
+		}
+	}
+	
+	/// A hypersphere (3-sphere), inscribed in `rect`.
+	pub fn hypersphere(block_id:u8, rect:Rect4, style:ShapeStyle) -> Self {
+		Self::shape(block_id, rect, style, |u| u.iter().map(|n| n*n).sum::<f64>() <= 1.0)
+	}
+	
+	/// A spherinder (a sphere extruded along `axis`), inscribed in `rect`.
+	pub fn spherinder(block_id:u8, rect:Rect4, axis:Axis, style:ShapeStyle) -> Self {
+		Self::shape(block_id, rect, style, move |u| (0..4).filter(|&i| i != axis as usize).map(|i| u[i]*u[i]).sum::<f64>() <= 1.0)
+	}
+	
+	/// A cubinder (a disk in `plane`, extruded along both of the other axes), inscribed in `rect`.
+	pub fn cubinder(block_id:u8, rect:Rect4, plane:Plane, style:ShapeStyle) -> Self {
+		let (a, b) = plane.axes();
+		Self::shape(block_id, rect, style, move |u| u[a as usize]*u[a as usize] + u[b as usize]*u[b as usize] <= 1.0)
+	}
+	
+	/// A duocylinder (the product of a disk in `plane` and a disk in the plane perpendicular to it), inscribed in `rect`.
+	pub fn duocylinder(block_id:u8, rect:Rect4, plane:Plane, style:ShapeStyle) -> Self {
+		let (a, b) = plane.axes();
+		let mut others = (0..4).filter(move |&i| i != a as usize && i != b as usize);
+		let (c, d)     = (others.next().unwrap(), others.next().unwrap());
+		Self::shape(block_id, rect, style, move |u| {
+			u[a as usize]*u[a as usize] + u[b as usize]*u[b as usize] <= 1.0 && u[c]*u[c] + u[d]*u[d] <= 1.0
+		})
+	}
+	
+	/// A hypercone (a spherical cone), inscribed in `rect`,
+	///  with its base at the start of `rect` along `axis` and its apex at the end.
+	pub fn hypercone(block_id:u8, rect:Rect4, axis:Axis, style:ShapeStyle) -> Self {
+		Self::shape(block_id, rect, style, move |u| {
+			let r = (1.0 - u[axis as usize]) / 2.0;
+			(0..4).filter(|&i| i != axis as usize).map(|i| u[i]*u[i]).sum::<f64>() <= r*r
+		})
+	}
+	
+	/// A (right-angled) 4D simplex (a pentachoron), inscribed in `rect`,
+	///  with its right-angled corner at the start of `rect`.
+	pub fn simplex(block_id:u8, rect:Rect4, style:ShapeStyle) -> Self {
+		Self::shape(block_id, rect, style, |u| u.iter().map(|n| (n+1.0) / 2.0).sum::<f64>() <= 1.0)
+	}
+	
+	/// Fills with a random mixture of blocks, given as pairs of a block ID and its (relative) weight.
+	/// 
+	/// For example, `[(Stone, 7), (IronOre, 2), (Dirt, 1)]` is roughly 70% Stone, 20% Iron Ore and 10% Dirt.
+	pub fn random(seed:u64, weights:&'a [(u8, u32)], rect:Rect4) -> Self {
+		let weights = Arc::<[(u8, u32)]>::from(weights);
+		let total   = weights.iter().map(|&(_, weight)| weight as u64).sum::<u64>();
+		Self {
+			determiner: Box::new(move |_, p| {
+				if total == 0 { return None; }
+				
+				let mut pick = hash4(seed, p) % total;
+				for &(block_id, weight) in weights.iter() {
+					if pick < weight as u64 { return Some(block_id); }
+					pick -= weight as u64;
+				}
+				None
+			}),
+			rect,
+			_lt: PhantomData
+		}
+	}
+	
+	/// Fills with a gradient along `axis`, blending (randomly) between each of the specified blocks
+	///  from the start of `rect` to its end.
+	pub fn gradient(seed:u64, block_ids:&'a [u8], axis:Axis, rect:Rect4) -> Self {
+		let block_ids = Arc::<[u8]>::from(block_ids);
+		let (start, size) = (rect.normalized().start, rect.size());
+		let (start, size) = ([start.0, start.1, start.2, start.3][axis as usize], [size.0, size.1, size.2, size.3][axis as usize]);
+		Self {
+			determiner: Box::new(move |loc, p| {
+				let last = block_ids.len().checked_sub(1)?;
+				let t    = ([loc.0, loc.1, loc.2, loc.3][axis as usize]-start) as f64 / (size-1).max(1) as f64 * last as f64;
+				let band = (t.floor() as usize).min(last);
+				Some(block_ids[if unit4(seed, p) < t-band as f64 { band+1 } else { band }])
+			}),
+			rect,
+			_lt: PhantomData
+		}
+	}
+	
+	/// Fills with a 4D checkerboard of two blocks, made of cells which are `cell_size` blocks wide along each axis.
+	pub fn checkerboard(block_ids:(u8, u8), cell_size:usize, rect:Rect4) -> Self {
+		let n = cell_size.max(1) as i64;
+		Self {
+			determiner: Box::new(move |_, p| {
+				let parity = p.0.div_euclid(n) + p.1.div_euclid(n) + p.2.div_euclid(n) + p.3.div_euclid(n);
+				Some(if parity.rem_euclid(2) == 0 { block_ids.0 } else { block_ids.1 })
+			}),
+			rect,
+			_lt: PhantomData
+		}
+	}
+	
+	/// Masks these parameters with noise, leaving blocks where the noise is below `threshold` (from `0.0` to `1.0`) as they are.
+	/// 
+	/// The noise has features roughly `scale` blocks across.
+	pub fn masked_by_noise(mut self, seed:u64, scale:f64, threshold:f64) -> Self {
+		Self {
+			determiner: Box::new(move |loc, p| {
+				let n = fractal4(seed, (p.0 as f64 / scale, p.1 as f64 / scale, p.2 as f64 / scale, p.3 as f64 / scale), 3);
+				if n < threshold { None } else { self.determiner.as_mut()(loc, p) }
+			}),
+			rect: self.rect,
+			_lt: PhantomData
+		}
+	}
+	
+	/// Combines these parameters with another set, covering every block either of them covers.
+	/// 
+	/// Where both cover a block, these parameters decide which block is placed.
+	pub fn union(self, other:FillParams<'a>) -> Self {
+		let rect = self.rect.union(&other.rect);
+		self.combine(other, rect, |a, b| a.or_else(b))
+	}
+	
+	/// Combines these parameters with another set, covering only the blocks both of them cover.
+	/// 
+	/// These parameters decide which block is placed.
+	pub fn intersection(self, other:FillParams<'a>) -> Self {
+		let rect = self.rect.intersection(&other.rect).unwrap_or(self.rect);
+		self.combine(other, rect, |a, b| a.filter(|_| b().is_some()))
+	}
+	
+	/// Combines these parameters with another set, covering only the blocks these parameters cover and the other does not.
+	/// 
+	/// Blocks covered by the other set are left as they are; to carve them out, [`union`](FillParams::union) air over them instead.
+	pub fn difference(self, other:FillParams<'a>) -> Self {
+		let rect = self.rect;
+		self.combine(other, rect, |a, b| a.filter(|_| b().is_none()))
+	}
+	
+	/// Combines these parameters with another set, covering only the blocks exactly one of them covers.
+	pub fn xor(self, other:FillParams<'a>) -> Self {
+		let rect = self.rect.union(&other.rect);
+		self.combine(other, rect, |a, b| match (a, b()) {
+			(Some(id), None) | (None, Some(id)) => Some(id),
+			_ => None
+		})
+	}
+	
+	/// Combines these parameters with another set, according to an operator which is given
+	///  the block chosen by these parameters and a function to get the block chosen by the other.
+	/// 
+	/// Neither set of parameters is consulted about blocks outside of its area.
+	fn combine<F:Fn(Option<u8>, &mut dyn FnMut()->Option<u8>)->Option<u8>+'static>(mut self, mut other:FillParams<'a>, rect:Rect4, op:F) -> Self {
+		let (a_rect, b_rect) = (self.rect, other.rect);
+		Self {
+			determiner: Box::new(move |loc, p| {
+				let a = if a_rect.contains(loc) { self.determiner.as_mut()(loc, p) } else { None };
+				op(a, &mut || if b_rect.contains(loc) { other.determiner.as_mut()(loc, p) } else { None })
+			}),
+			rect,
+			_lt: PhantomData
+		}
+	}
+	
+	/// Creates [`FillParams`] for a shape, given a function which decides if a point is inside of it.
+	/// 
+	/// Points passed to `inside` are the centers of blocks, scaled such that `rect` spans from `-1.0` to `1.0` along each axis.  
+	/// Blocks outside of the shape are left as they are.
+	fn shape<F:Fn([f64; 4])->bool+'static>(block_id:u8, rect:Rect4, style:ShapeStyle, inside:F) -> Self {
+		let (start, size) = (rect.normalized().start, rect.size());
+		let (start, size) = ([start.0, start.1, start.2, start.3], [size.0, size.1, size.2, size.3]);
+		
+		let contains = move |p:[i64; 4]| {
+			let mut u = [0.0; 4];
+			for i in 0..4 {
+				if p[i] < start[i] as i64 || p[i] >= (start[i]+size[i]) as i64 { return false; }
+				u[i] = ((p[i]-start[i] as i64) as f64 + 0.5) / size[i] as f64 * 2.0 - 1.0;
+			}
+			inside(u)
+		};
+		
+		Self {
+			determiner: Box::new(move |loc, _| {
+				let p = [loc.0 as i64, loc.1 as i64, loc.2 as i64, loc.3 as i64];
+				if !contains(p) { return None; }
+				
+				match style {
+					ShapeStyle::Solid => Some(block_id),
+					ShapeStyle::Shell(thickness) => {
+						let t = thickness.max(1) as i64;
+						let on_shell = (0..4).any(|i| [-t, t].into_iter().any(|d| {
+							let mut q = p;
+							q[i] += d;
+							!contains(q)
+						}));
+						Some(if on_shell { block_id } else { 0 })
+					}
+				}
+			}),
 			rect,
 			_lt: PhantomData
 		}
@@ -93,30 +307,301 @@ impl<'a> FillParams<'a> {
 
 
 
+/// How much of a shape is filled in.
+#[derive(PartialEq, Clone, Debug, Copy, Hash, Eq)]
+pub enum ShapeStyle {
+	/// The whole shape is filled.
+	Solid,
+	/// Only the outer layer of the shape, of the given thickness, is filled, and the inside of the shape is filled with air.
+	/// 
+	/// A thickness of `0` is treated as `1`, the thinnest shell there is, rather than leaving nothing but air.
+	Shell(usize)
+}
+
+
+
+/// The number of points between two coordinates which are `diff` apart (inclusive), saturating at [`usize::MAX`].
+#[inline(always)] const fn span(diff:u128) -> usize {
+	if diff >= usize::MAX as u128 { usize::MAX } else { diff as usize + 1 }
+}
+
+/// Implements the parts of [`Rect4`] and [`WorldRect4`] which only differ by the type of their coordinates
+///  (alongside an iterator over their points), given that type and the `const` functions which pick the lesser or greater of two coordinates.
+macro_rules! impl_rect4 {
+	($rect:ident, $iter:ident, $coord:ty, $min:ident, $max:ident) => {
+		impl $rect {
+			#[inline(always)] pub const fn new(start:($coord, $coord, $coord, $coord), end:($coord, $coord, $coord, $coord)) -> Self { Self {start, end} }
+			
+			#[doc = concat!("Creates a new, [normalized](", stringify!($rect), "::normalized), [`", stringify!($rect), "`] spanning between two corners.")]
+			#[inline(always)] pub const fn from_corners(a:($coord, $coord, $coord, $coord), b:($coord, $coord, $coord, $coord)) -> Self { Self::new(a, b).normalized() }
+			
+			/// Returns a copy of this area with its corners reordered such that `start` is its lowest corner, and `end` its highest.
+			pub const fn normalized(&self) -> Self {
+				Self {
+					start: ($min(self.start.0, self.end.0), $min(self.start.1, self.end.1), $min(self.start.2, self.end.2), $min(self.start.3, self.end.3)),
+					end:   ($max(self.start.0, self.end.0), $max(self.start.1, self.end.1), $max(self.start.2, self.end.2), $max(self.start.3, self.end.3))
+				}
+			}
+			
+			/// The size of this area along the X, Y, Z and W axes,
+			///  each of which saturates at [`usize::MAX`] (rather than overflowing) for areas that span more than that.
+			pub const fn size(&self) -> (usize, usize, usize, usize) {
+				(
+					span(self.start.0.abs_diff(self.end.0) as u128),
+					span(self.start.1.abs_diff(self.end.1) as u128),
+					span(self.start.2.abs_diff(self.end.2) as u128),
+					span(self.start.3.abs_diff(self.end.3) as u128)
+				)
+			}
+			
+			/// The number of blocks in this area, saturating at [`usize::MAX`].
+			#[inline(always)] pub const fn volume(&self) -> usize {
+				let size = self.size();
+				size.0.saturating_mul(size.1).saturating_mul(size.2).saturating_mul(size.3)
+			}
+			
+			/// Whether a point lies within this area.
+			pub const fn contains(&self, point:($coord, $coord, $coord, $coord)) -> bool {
+				let r = self.normalized();
+				point.0 >= r.start.0 && point.0 <= r.end.0 && point.1 >= r.start.1 && point.1 <= r.end.1 &&
+				point.2 >= r.start.2 && point.2 <= r.end.2 && point.3 >= r.start.3 && point.3 <= r.end.3
+			}
+			
+			/// Whether another area lies entirely within this area.
+			#[inline(always)] pub const fn contains_rect(&self, other:&Self) -> bool { self.contains(other.start) && self.contains(other.end) }
+			
+			/// The area shared by this area and another, if they overlap.
+			pub const fn intersection(&self, other:&Self) -> Option<Self> {
+				let (a, b) = (self.normalized(), other.normalized());
+				let r = Self {
+					start: ($max(a.start.0, b.start.0), $max(a.start.1, b.start.1), $max(a.start.2, b.start.2), $max(a.start.3, b.start.3)),
+					end:   ($min(a.end.0, b.end.0), $min(a.end.1, b.end.1), $min(a.end.2, b.end.2), $min(a.end.3, b.end.3))
+				};
+				if r.start.0 > r.end.0 || r.start.1 > r.end.1 || r.start.2 > r.end.2 || r.start.3 > r.end.3 { None } else { Some(r) }
+			}
+			
+			/// The smallest area which contains both this area and another.
+			pub const fn union(&self, other:&Self) -> Self {
+				let (a, b) = (self.normalized(), other.normalized());
+				Self {
+					start: ($min(a.start.0, b.start.0), $min(a.start.1, b.start.1), $min(a.start.2, b.start.2), $min(a.start.3, b.start.3)),
+					end:   ($max(a.end.0, b.end.0), $max(a.end.1, b.end.1), $max(a.end.2, b.end.2), $max(a.end.3, b.end.3))
+				}
+			}
+			
+			/// Returns an iterator over every point in this area, in X, Y, Z, W order.
+			#[inline(always)] pub const fn iter(&self) -> $iter { $iter::new(*self) }
+		}
+		
+		impl IntoIterator for $rect {
+			type IntoIter = $iter;
+			type Item     = ($coord, $coord, $coord, $coord);
+			
+			#[inline(always)] fn into_iter(self) -> Self::IntoIter { $iter::new(self) }
+		}
+		
+		
+		
+		#[doc = concat!("An iterator over every point in a [`", stringify!($rect), "`].")]
+		#[derive(Clone, Debug)]
+		pub struct $iter {
+			rect:$rect,
+			next:Option<($coord, $coord, $coord, $coord)>
+		}
+		
+		impl $iter {
+			#[inline(always)] pub const fn new(rect:$rect) -> Self {
+				let rect = rect.normalized();
+				Self {
+					rect,
+					next: Some(rect.start)
+				}
+			}
+		}
+		
+		impl Iterator for $iter {
+			type Item = ($coord, $coord, $coord, $coord);
+			
+			fn next(&mut self) -> Option<Self::Item> {
+				let (s, e) = (self.rect.start, self.rect.end);
+				let cur    = self.next?;
+				
+				self.next = if cur.3 < e.3      { Some((cur.0, cur.1, cur.2, cur.3+1)) }
+				            else if cur.2 < e.2 { Some((cur.0, cur.1, cur.2+1, s.3)) }
+				            else if cur.1 < e.1 { Some((cur.0, cur.1+1, s.2, s.3)) }
+				            else if cur.0 < e.0 { Some((cur.0+1, s.1, s.2, s.3)) }
+				            else                { None };
+				Some(cur)
+			}
+		}
+	};
+}
+
+
+
 /// A (4D) rectangular area.
-#[derive(PartialEq, Clone, Debug, Copy, Eq)]
+/// 
+/// Both corners are inclusive, and may be given in any order;
+///  methods which depend on the order of the corners [normalize](Rect4::normalized) the area first.
+#[derive(PartialEq, Clone, Debug, Copy, Hash, Eq)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
 #[repr(C)]
 pub struct Rect4 {
 	pub start:(usize, usize, usize, usize),
 	pub end:(usize, usize, usize, usize)
 }
 
+impl_rect4!(Rect4, Rect4Iter, usize, umin, umax);
+
 impl Rect4 {
-	#[inline(always)] pub const fn new(start:(usize, usize, usize, usize), end:(usize, usize, usize, usize)) -> Self { Self {start, end} }
+	/// Creates a new [`Rect4`] of the given size (which must not be zero along any axis) with its lowest corner at `start`.
+	/// 
+	/// The far corner stops at [`usize::MAX`] along any axis it would go past it on.
+	pub const fn with_size(start:(usize, usize, usize, usize), size:(usize, usize, usize, usize)) -> Self {
+		const fn end(start:usize, size:usize) -> usize { start.saturating_add(size.saturating_sub(1)) }
+		Self::new(start, (end(start.0, size.0), end(start.1, size.1), end(start.2, size.2), end(start.3, size.3)))
+	}
+	
+	/// Grows this area by `n` blocks in every direction (stopping at zero and [`usize::MAX`]).
+	pub const fn expand(&self, n:usize) -> Self {
+		let r = self.normalized();
+		Self {
+			start: (r.start.0.saturating_sub(n), r.start.1.saturating_sub(n), r.start.2.saturating_sub(n), r.start.3.saturating_sub(n)),
+			end:   (r.end.0.saturating_add(n), r.end.1.saturating_add(n), r.end.2.saturating_add(n), r.end.3.saturating_add(n))
+		}
+	}
+	
+	/// Shrinks this area by `n` blocks in every direction, if anything would be left of it.
+	pub const fn shrink(&self, n:usize) -> Option<Self> {
+		let r = self.normalized();
+		if (r.end.0-r.start.0)/2 < n || (r.end.1-r.start.1)/2 < n || (r.end.2-r.start.2)/2 < n || (r.end.3-r.start.3)/2 < n { return None; }
+		Some(Self {
+			start: (r.start.0+n, r.start.1+n, r.start.2+n, r.start.3+n),
+			end:   (r.end.0-n, r.end.1-n, r.end.2-n, r.end.3-n)
+		})
+	}
+	
+	/// Moves this area by an offset, if it would not end up with negative coordinates.
+	pub const fn translate(&self, by:(i64, i64, i64, i64)) -> Option<Self> {
+		let r = WorldRect4::from_rect(*self).translate(by).normalized();
+		if r.start.0 < 0 || r.start.1 < 0 || r.start.2 < 0 || r.start.3 < 0 { return None; }
+		Some(Self::new(
+			(r.start.0 as usize, r.start.1 as usize, r.start.2 as usize, r.start.3 as usize),
+			(r.end.0 as usize, r.end.1 as usize, r.end.2 as usize, r.end.3 as usize)
+		))
+	}
 	
 	pub const fn has_on_perimeter(&self, point:(usize, usize, usize, usize)) -> bool {
-		((point.0 == self.start.0 || point.0 == self.end.0) && (
-			point.1 >= self.start.1 && point.1 <= self.end.1 && point.2 >= self.start.2 && point.2 <= self.end.2 &&
-			point.3 >= self.start.3 && point.3 <= self.end.3
-		)) || ((point.1 == self.start.1 || point.1 == self.end.1) && (
-			point.0 >= self.start.0 && point.0 <= self.end.0 && point.2 >= self.start.2 && point.2 <= self.end.2 &&
-			point.3 >= self.start.3 && point.3 <= self.end.3
-		)) || ((point.2 == self.start.2 || point.2 == self.end.2) && (
-			point.0 >= self.start.0 && point.0 <= self.end.0 && point.1 >= self.start.1 && point.1 <= self.end.1 &&
-			point.3 >= self.start.3 && point.3 <= self.end.3
-		)) || ((point.3 == self.start.3 || point.3 == self.end.3) && (
-			point.0 >= self.start.0 && point.0 <= self.end.0 && point.1 >= self.start.1 && point.1 <= self.end.1 &&
-			point.2 >= self.start.2 && point.2 <= self.end.2
+		let r = self.normalized();
+		((point.0 == r.start.0 || point.0 == r.end.0) && (
+			point.1 >= r.start.1 && point.1 <= r.end.1 && point.2 >= r.start.2 && point.2 <= r.end.2 &&
+			point.3 >= r.start.3 && point.3 <= r.end.3
+		)) || ((point.1 == r.start.1 || point.1 == r.end.1) && (
+			point.0 >= r.start.0 && point.0 <= r.end.0 && point.2 >= r.start.2 && point.2 <= r.end.2 &&
+			point.3 >= r.start.3 && point.3 <= r.end.3
+		)) || ((point.2 == r.start.2 || point.2 == r.end.2) && (
+			point.0 >= r.start.0 && point.0 <= r.end.0 && point.1 >= r.start.1 && point.1 <= r.end.1 &&
+			point.3 >= r.start.3 && point.3 <= r.end.3
+		)) || ((point.3 == r.start.3 || point.3 == r.end.3) && (
+			point.0 >= r.start.0 && point.0 <= r.end.0 && point.1 >= r.start.1 && point.1 <= r.end.1 &&
+			point.2 >= r.start.2 && point.2 <= r.end.2
 		))
 	}
-}
\ No newline at end of file
+}
+
+
+
+/// A (4D) rectangular area in world-space.
+/// 
+/// Both corners are inclusive, and may be given in any order;
+///  methods which depend on the order of the corners [normalize](WorldRect4::normalized) the area first.
+#[derive(PartialEq, Clone, Debug, Copy, Hash, Eq)]
+#[repr(C)]
+pub struct WorldRect4 {
+	pub start:(i64, i64, i64, i64),
+	pub end:(i64, i64, i64, i64)
+}
+
+impl_rect4!(WorldRect4, WorldRect4Iter, i64, imin, imax);
+
+impl WorldRect4 {
+	/// Creates a new [`WorldRect4`] of the given size (which must not be zero along any axis) with its lowest corner at `start`.
+	/// 
+	/// The far corner stops at [`i64::MAX`] along any axis it would go past it on.
+	pub const fn with_size(start:(i64, i64, i64, i64), size:(usize, usize, usize, usize)) -> Self {
+		const fn end(start:i64, size:usize) -> i64 {
+			let n = size.saturating_sub(1);
+			start.saturating_add(if n as u128 > i64::MAX as u128 { i64::MAX } else { n as i64 })
+		}
+		Self::new(start, (end(start.0, size.0), end(start.1, size.1), end(start.2, size.2), end(start.3, size.3)))
+	}
+	
+	/// Creates a new [`WorldRect4`] covering the same points as a [`Rect4`].
+	pub const fn from_rect(rect:Rect4) -> Self {
+		Self::new(
+			(rect.start.0 as i64, rect.start.1 as i64, rect.start.2 as i64, rect.start.3 as i64),
+			(rect.end.0 as i64, rect.end.1 as i64, rect.end.2 as i64, rect.end.3 as i64)
+		)
+	}
+	
+	/// Grows this area by `n` blocks in every direction (stopping at [`i64::MIN`] and [`i64::MAX`]).
+	pub const fn expand(&self, n:i64) -> Self {
+		let r = self.normalized();
+		Self {
+			start: (r.start.0.saturating_sub(n), r.start.1.saturating_sub(n), r.start.2.saturating_sub(n), r.start.3.saturating_sub(n)),
+			end:   (r.end.0.saturating_add(n), r.end.1.saturating_add(n), r.end.2.saturating_add(n), r.end.3.saturating_add(n))
+		}
+	}
+	
+	/// Shrinks this area by `n` blocks in every direction, if anything would be left of it.
+	pub const fn shrink(&self, n:i64) -> Option<Self> {
+		let r = self.normalized();
+		let (n2, r2) = (2*n as i128, (r.start.0 as i128, r.start.1 as i128, r.start.2 as i128, r.start.3 as i128));
+		if r.end.0 as i128-r2.0 < n2 || r.end.1 as i128-r2.1 < n2 || r.end.2 as i128-r2.2 < n2 || r.end.3 as i128-r2.3 < n2 { return None; }
+		Some(r.expand(n.saturating_neg()))
+	}
+	
+	/// Moves this area by an offset.
+	pub const fn translate(&self, by:(i64, i64, i64, i64)) -> Self {
+		Self {
+			start: (self.start.0+by.0, self.start.1+by.1, self.start.2+by.2, self.start.3+by.3),
+			end:   (self.end.0+by.0, self.end.1+by.1, self.end.2+by.2, self.end.3+by.3)
+		}
+	}
+	
+	/// Splits this area into the parts of it which lie within each chunk,
+	///  as the position of the chunk (in chunk-space) and the area within that chunk.
+	/// 
+	/// Any part of this area which is above or below the world is left out.
+	pub fn split_chunks(&self) -> impl Iterator<Item=((i64, i64, i64), Rect4)> + use<> {
+		let (w, l, h) = (Chunk::WIDTH as i64, Chunk::LENGTH as i64, Chunk::WETH as i64);
+		let r = self.normalized().intersection(&Self::new((i64::MIN, 0, i64::MIN, i64::MIN), (i64::MAX, World::HEIGHT as i64-1, i64::MAX, i64::MAX)));
+		
+		r.into_iter().flat_map(move |r| {
+			(r.start.0.div_euclid(w)..=r.end.0.div_euclid(w)).flat_map(move |cx| {
+				(r.start.2.div_euclid(l)..=r.end.2.div_euclid(l)).flat_map(move |cz| {
+					(r.start.3.div_euclid(h)..=r.end.3.div_euclid(h)).map(move |cw| {
+						let base = (cx*w, 0, cz*l, cw*h);
+						((cx, cz, cw), Rect4::new(
+							((imax(r.start.0, base.0)-base.0) as usize, r.start.1 as usize, (imax(r.start.2, base.2)-base.2) as usize, (imax(r.start.3, base.3)-base.3) as usize),
+							((imin(r.end.0, base.0+w-1)-base.0) as usize, r.end.1 as usize, (imin(r.end.2, base.2+l-1)-base.2) as usize, (imin(r.end.3, base.3+h-1)-base.3) as usize)
+						))
+					})
+				})
+			})
+		})
+	}
+}
+
+impl From<Rect4> for WorldRect4 {
+	#[inline(always)] fn from(v:Rect4) -> Self { Self::from_rect(v) }
+}
+
+
+
+
+
+#[inline(always)] const fn umin(a:usize, b:usize) -> usize { if a < b { a } else { b } }
+#[inline(always)] const fn umax(a:usize, b:usize) -> usize { if a > b { a } else { b } }
+#[inline(always)] const fn imin(a:i64, b:i64) -> i64 { if a < b { a } else { b } }
+#[inline(always)] const fn imax(a:i64, b:i64) -> i64 { if a > b { a } else { b } }
\ No newline at end of file
@@ -0,0 +1,159 @@
+use crate::noise::{hash4, unit4};
+use crate::world::{Block, World};
+use crate::util::WorldRect4;
+use crate::biome::Biome;
+
+use core::convert::TryFrom;
+
+
+
+
+
+/// A tree, or another plant, which can be grown in a [`World`].
+/// 
+/// Plants spread out through all four dimensions, and across chunk borders,
+///  but only ever replace air in chunks which are loaded.
+#[derive(PartialEq, Clone, Debug, Copy, Hash, Eq)]
+pub enum Plant {
+	/// A tree of [`Block::Wood`] and [`Block::Leaf`].
+	Tree,
+	/// A tree of [`Block::MidnightWood`] and [`Block::MidnightLeaf`].
+	MidnightTree,
+	/// A tree of [`Block::Wood`] and [`Block::SnowyLeaf`].
+	SnowyTree,
+	/// A short column of [`Block::Cactus`].
+	Cactus,
+	/// A single [`Block::Bush`].
+	Bush,
+	/// A single [`Block::MidnightBush`].
+	MidnightBush,
+	/// A single [`Block::SnowyBush`].
+	SnowyBush,
+	/// A single red, white or blue flower.
+	Flower,
+	/// A single [`Block::TallGrass`].
+	TallGrass,
+	/// A scattering of [`Block::Pumpkin`]s on the surrounding ground.
+	PumpkinPatch
+}
+
+impl Plant {
+	/// The plants which grow in a biome, alongside their (relative) weights.
+	pub const fn for_biome(biome:Biome) -> &'static [(Self, u32)] {
+		match biome {
+			Biome::Grassland => &[(Self::TallGrass, 40), (Self::Flower, 20), (Self::Bush, 15), (Self::Tree, 20), (Self::PumpkinPatch, 1)],
+			Biome::Midnight  => &[(Self::MidnightBush, 50), (Self::MidnightTree, 30)],
+			Biome::Desert    => &[(Self::Cactus, 1)],
+			Biome::Snow      => &[(Self::SnowyBush, 40), (Self::SnowyTree, 30)]
+		}
+	}
+	
+	/// Grows this plant with its base at the specified location in world-space,
+	///  returning the number of blocks that were placed.
+	/// 
+	/// The `seed` decides the details of the plant, such as its height and the shape of its leaves.
+	pub fn grow(&self, world:&mut World, seed:u64, base:(i64, i64, i64, i64)) -> usize {
+		world.batch(|world| {
+			let (x, y, z, w) = base;
+			match self {
+				Self::Tree         => grow_tree(world, seed, base, Block::Wood, Block::Leaf),
+				Self::MidnightTree => grow_tree(world, seed, base, Block::MidnightWood, Block::MidnightLeaf),
+				Self::SnowyTree    => grow_tree(world, seed, base, Block::Wood, Block::SnowyLeaf),
+				Self::Cactus       => {
+					let height = 2 + hash4(seed, base) % 3;
+					(0..height as i64).map(|dy| place(world, (x, y+dy, z, w), Block::Cactus)).sum()
+				}
+				Self::Bush         => place(world, base, Block::Bush),
+				Self::MidnightBush => place(world, base, Block::MidnightBush),
+				Self::SnowyBush    => place(world, base, Block::SnowyBush),
+				Self::Flower       => place(world, base, [Block::RedFlower, Block::WhiteFlower, Block::BlueFlower][(hash4(seed, base) % 3) as usize]),
+				Self::TallGrass    => place(world, base, Block::TallGrass),
+				Self::PumpkinPatch => {
+					let mut placed = 0;
+					for (dx, dz, dw) in cube(2) {
+						let at = (x+dx, y, z+dz, w+dw);
+						if unit4(seed, at) < 0.15 && world.get_block((at.0, y-1, at.2, at.3)).is_some_and(|id| id == Block::Grass) {
+							placed += place(world, at, Block::Pumpkin);
+						}
+					}
+					placed
+				}
+			}
+		})
+	}
+}
+
+
+
+/// Grows plants on the ground within an area of a world, returning the number of plants that were grown.
+/// 
+/// The ground of each (X, Z, W) column is its highest block with air above it,
+///  and the plants that grow there are decided by the [biome its surface belongs to](Biome::from_surface).  
+/// Roughly `density` (from `0.0` to `1.0`) of the columns grow a plant, as decided by the `seed`.
+pub fn decorate(world:&mut World, seed:u64, area:WorldRect4, density:f64) -> usize {
+	let area = area.normalized();
+	world.batch(|world| {
+		let mut grown = 0;
+		for x in area.start.0..=area.end.0 { for z in area.start.2..=area.end.2 { for w in area.start.3..=area.end.3 {
+			if unit4(seed, (x, -1, z, w)) >= density { continue; }
+			
+			let ground = (area.start.1..area.end.1).rev().find(|&y| {
+				world.get_block((x, y, z, w)).is_some_and(|id| id != Block::Air) && world.get_block((x, y+1, z, w)) == Some(Block::Air as u8)
+			});
+			let Some(y)     = ground else { continue; };
+			let Some(biome) = world.get_block((x, y, z, w)).and_then(|id| Block::try_from(id).ok()).and_then(Biome::from_surface) else { continue; };
+			
+			let Some(plant) = pick(Plant::for_biome(biome), hash4(seed, (x, -2, z, w))) else { continue; };
+			if plant.grow(world, seed, (x, y+1, z, w)) > 0 { grown += 1; }
+		} } }
+		grown
+	})
+}
+
+
+
+
+
+/// Places a block where there is (loaded) air, returning `1` if it was placed.
+fn place(world:&mut World, at:(i64, i64, i64, i64), block:Block) -> usize {
+	if world.get_block(at) != Some(Block::Air as u8) { return 0; }
+	world.set_block(at, block as u8);
+	1
+}
+
+/// Picks one of a number of plants, alongside their (relative) weights, using a random number.
+fn pick(plants:&[(Plant, u32)], random:u64) -> Option<Plant> {
+	let total = plants.iter().map(|&(_, weight)| weight as u64).sum::<u64>();
+	if total == 0 { return None; }
+	
+	let mut pick = random % total;
+	for &(plant, weight) in plants {
+		if pick < weight as u64 { return Some(plant); }
+		pick -= weight as u64;
+	}
+	None
+}
+
+/// Grows a tree with a trunk of `wood`, topped with a (4D) ball of `leaf`.
+fn grow_tree(world:&mut World, seed:u64, base:(i64, i64, i64, i64), wood:Block, leaf:Block) -> usize {
+	let (x, y, z, w) = base;
+	let height = 4 + (hash4(seed, base) % 3) as i64;
+	
+	let mut placed = 0;
+	for dy in 0..height { placed += place(world, (x, y+dy, z, w), wood); }
+	
+	let top = y + height - 1;
+	for dy in -2..=2_i64 {
+		for (dx, dz, dw) in cube(2) {
+			let d  = dx*dx + dy*dy + dz*dz + dw*dw;
+			let at = (x+dx, top+dy, z+dz, w+dw);
+			if d <= 4 || (d <= 6 && unit4(seed, at) < 0.5) { placed += place(world, at, leaf); }
+		}
+	}
+	placed
+}
+
+/// Every (X, Z, W) offset within `r` blocks along each axis.
+fn cube(r:i64) -> impl Iterator<Item=(i64, i64, i64)> {
+	(-r..=r).flat_map(move |dx| (-r..=r).flat_map(move |dz| (-r..=r).map(move |dw| (dx, dz, dw))))
+}
\ No newline at end of file
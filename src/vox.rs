@@ -0,0 +1,238 @@
+use crate::world::{Block, World};
+use crate::err::VoxError;
+
+use core::convert::{TryFrom, TryInto};
+use core::default::Default;
+use core::ops::FnMut;
+use std::vec::Vec;
+
+
+
+
+
+/// A palette which maps block IDs onto the (RGBA) colors used to represent them in a `.vox` model.
+/// 
+/// Block IDs double as color indices, so an exported voxel's color index is the ID of its block.
+#[derive(PartialEq, Clone, Debug, Eq)]
+#[repr(transparent)]
+pub struct VoxPalette([Option<[u8; 4]>; 256]);
+
+impl VoxPalette {
+	/// Gets the color used for a block of the specified ID, if it has one.
+	#[inline(always)] pub const fn color(&self, block_id:u8) -> Option<[u8; 4]> { self.0[block_id as usize] }
+	
+	/// Sets (or, with [`None`], clears) the color used for a block of the specified ID.
+	#[inline(always)] pub const fn set_color(&mut self, block_id:u8, color:Option<[u8; 4]>) { self.0[block_id as usize] = color; }
+	
+	/// Finds the ID of the (non-air) block whose color is closest to the specified color.
+	/// 
+	/// Returns [`None`] if no block, besides air, has a color.
+	pub fn nearest_block(&self, color:[u8; 4]) -> Option<u8> {
+		self.0.iter().enumerate().skip(1)
+			.filter_map(|(id, c)| Some((id as u8, (*c)?)))
+			.min_by_key(|(_, c)| c.iter().zip(color).map(|(&a, b)| (a as i32-b as i32).pow(2)).sum::<i32>())
+			.map(|(id, _)| id)
+	}
+}
+
+impl Default for VoxPalette {
+	/// Creates a palette using the [color](Block::color) of every [`Block`].
+	fn default() -> Self {
+		let mut colors = [None; 256];
+		for (id, color) in colors.iter_mut().enumerate().skip(1) {
+			*color = Block::try_from(id as u8).ok().map(|block| block.color());
+		}
+		Self(colors)
+	}
+}
+
+
+
+/// A (single) MagicaVoxel model.
+/// 
+/// Models are Z-up, so a model's X, Y and Z axes correspond to the world's X, Z and Y axes, respectively.
+#[derive(PartialEq, Clone, Debug, Eq)]
+pub struct VoxModel {
+	/// The size of the model along its X, Y and Z axes.
+	pub size:(usize, usize, usize),
+	/// Every (non-empty) voxel in the model, as an X, Y, Z and color index.
+	pub voxels:Vec<(u8, u8, u8, u8)>,
+	/// The (RGBA) color of each color index; index `0` is unused.
+	pub palette:[[u8; 4]; 256]
+}
+
+impl VoxModel {
+	/// The version of the `.vox` format that is written.
+	pub const VERSION:u32 = 150;
+	
+	/// MagicaVoxel's built-in palette, which models without an `RGBA` chunk use.
+	/// 
+	/// As listed in the `.vox` format's specification, it holds every color whose components are each one of
+	///  `FF`, `CC`, `99`, `66`, `33` or `00` (from white down to, but not including, black; blue changing fastest and red slowest),
+	///  followed by ramps of red, green, blue and grey through `EE`, `DD`, `BB`, `AA`, `88`, `77`, `55`, `44`, `22` and `11`.
+	pub const DEFAULT_PALETTE:[[u8; 4]; 256] = {
+		const STEPS:[u8; 6]  = [0xFF, 0xCC, 0x99, 0x66, 0x33, 0x00];
+		const RAMP:[u8; 10]  = [0xEE, 0xDD, 0xBB, 0xAA, 0x88, 0x77, 0x55, 0x44, 0x22, 0x11];
+		
+		let mut colors = [[0; 4]; 256];
+		let mut idx    = 1;
+		while idx < 216 {
+			let k = idx-1;
+			colors[idx] = [STEPS[k / 36], STEPS[(k/6) % 6], STEPS[k % 6], 0xFF];
+			idx += 1;
+		}
+		while idx < 256 {
+			let v = RAMP[(idx-216) % 10];
+			colors[idx] = match (idx-216) / 10 {
+				0 => [v, 0, 0, 0xFF],
+				1 => [0, v, 0, 0xFF],
+				2 => [0, 0, v, 0xFF],
+				_ => [v, v, v, 0xFF]
+			};
+			idx += 1;
+		}
+		colors
+	};
+	
+	/// Captures a 3D slice of a world, at the W coordinate `w`, between two (X, Y, Z) points.
+	/// 
+	/// Blocks without a color in the `palette`, such as air, are left out of the model,
+	///  as are blocks in chunks that are not loaded.
+	pub fn from_world(world:&World, w:i64, start:(i64, i64, i64), end:(i64, i64, i64), palette:&VoxPalette) -> Result<Self, VoxError> {
+		let (sx, sy, sz) = (start.0.min(end.0), start.1.min(end.1), start.2.min(end.2));
+		let (dx, dy, dz) = (start.0.max(end.0), start.1.max(end.1), start.2.max(end.2));
+		
+		let len  = |a:i64, b:i64| usize::try_from(a.abs_diff(b)).ok().and_then(|d| d.checked_add(1)).unwrap_or(usize::MAX);
+		let size = (len(dx, sx), len(dz, sz), len(dy, sy));
+		if size.0 > 256 || size.1 > 256 || size.2 > 256 { return Err(VoxError::TooLarge {size}); }
+		
+		let mut voxels = Vec::new();
+		for x in sx..=dx {
+			for y in sy..=dy {
+				for z in sz..=dz {
+					let Some(id) = world.get_block((x, y, z, w)) else { continue; };
+					if palette.color(id).is_some() && id != 0 {
+						voxels.push(((x-sx) as u8, (z-sz) as u8, (y-sy) as u8, id));
+					}
+				}
+			}
+		}
+		
+		let mut colors = [[0; 4]; 256];
+		for (idx, color) in colors.iter_mut().enumerate() {
+			*color = palette.color(idx as u8).unwrap_or_default();
+		}
+		
+		Ok(Self {size, voxels, palette:colors})
+	}
+	
+	/// Places this model into a world, in the W layer of `origin`,
+	///  with the model's first corner at `origin`.
+	/// 
+	/// The `mapper` decides which block (if any) each voxel's color becomes.
+	/// Returns the number of blocks that were placed.
+	pub fn place_into<F:FnMut([u8; 4])->Option<u8>>(&self, world:&mut World, origin:(i64, i64, i64, i64), mut mapper:F) -> usize {
+		world.batch(|world| {
+			let mut placed = 0;
+			for &(x, y, z, idx) in &self.voxels {
+				let Some(id) = mapper(self.palette[idx as usize]) else { continue; };
+				if world.set_block((origin.0+x as i64, origin.1+z as i64, origin.2+y as i64, origin.3), id).is_some() {
+					placed += 1;
+				}
+			}
+			placed
+		})
+	}
+	
+	/// Reads the first model in a `.vox` file.
+	/// 
+	/// If the file has no palette, MagicaVoxel's [default palette](VoxModel::DEFAULT_PALETTE) is used.
+	pub fn from_bytes(bytes:&[u8]) -> Result<Self, VoxError> {
+		#[inline(always)] fn read_u32(bytes:&[u8], at:usize) -> Result<u32, VoxError> {
+			Ok(u32::from_le_bytes(bytes.get(at..at+4).ok_or(VoxError::Truncated(at))?.try_into().unwrap()))
+		}
+		
+		if bytes.get(0..4) != Some(b"VOX ") { return Err(VoxError::InvalidMagic); }
+		
+		let mut size    = None;
+		let mut voxels  = None;
+		let mut palette = None;
+		
+		let mut pos = 8;
+		while pos < bytes.len() {
+			let id       = bytes.get(pos..pos+4).ok_or(VoxError::Truncated(pos))?;
+			let content  = read_u32(bytes, pos+4)? as usize;
+			let children = read_u32(bytes, pos+8)? as usize;
+			let data     = bytes.get(pos+12..pos+12+content).ok_or(VoxError::Truncated(pos))?;
+			
+			match id {
+				// The children of `MAIN` are every other chunk, so they are stepped into.
+				b"MAIN" => { pos += 12+content; continue; }
+				
+				b"SIZE" if size.is_none() => size = Some((
+					read_u32(data, 0)? as usize,
+					read_u32(data, 4)? as usize,
+					read_u32(data, 8)? as usize
+				)),
+				
+				b"XYZI" if size.is_some() && voxels.is_none() => {
+					let n = read_u32(data, 0)? as usize;
+					voxels = Some(
+						n.checked_mul(4).and_then(|len| data.get(4..4+len)).ok_or(VoxError::Truncated(pos))?
+							.chunks_exact(4)
+							.map(|v| (v[0], v[1], v[2], v[3]))
+							.collect::<Vec<_>>()
+					);
+				}
+				
+				b"RGBA" => {
+					let mut colors = [[0; 4]; 256];
+					for (idx, color) in data.chunks_exact(4).take(255).enumerate() {
+						colors[idx+1] = [color[0], color[1], color[2], color[3]];
+					}
+					palette = Some(colors);
+				}
+				
+				_ => ()
+			}
+			
+			pos += 12+content+children;
+		}
+		
+		let (Some(size), Some(voxels)) = (size, voxels) else { return Err(VoxError::MissingModel); };
+		Ok(Self {size, voxels, palette: palette.unwrap_or(Self::DEFAULT_PALETTE)})
+	}
+	
+	/// Serializes this model into the bytes of a `.vox` file.
+	pub fn to_bytes(&self) -> Vec<u8> {
+		#[inline(always)] fn push_chunk(out:&mut Vec<u8>, id:&[u8; 4], content:&[u8], children:usize) {
+			out.extend_from_slice(id);
+			out.extend_from_slice(&(content.len() as u32).to_le_bytes());
+			out.extend_from_slice(&(children as u32).to_le_bytes());
+			out.extend_from_slice(content);
+		}
+		
+		let mut size = Vec::with_capacity(12);
+		for n in [self.size.0, self.size.1, self.size.2] { size.extend_from_slice(&(n as u32).to_le_bytes()); }
+		
+		let mut xyzi = Vec::with_capacity(4+self.voxels.len()*4);
+		xyzi.extend_from_slice(&(self.voxels.len() as u32).to_le_bytes());
+		for &(x, y, z, idx) in &self.voxels { xyzi.extend_from_slice(&[x, y, z, idx]); }
+		
+		let mut rgba = Vec::with_capacity(1024);
+		for color in &self.palette[1..] { rgba.extend_from_slice(color); }
+		rgba.extend_from_slice(&[0; 4]);
+		
+		let mut children = Vec::new();
+		push_chunk(&mut children, b"SIZE", &size, 0);
+		push_chunk(&mut children, b"XYZI", &xyzi, 0);
+		push_chunk(&mut children, b"RGBA", &rgba, 0);
+		
+		let mut out = Vec::with_capacity(20+children.len());
+		out.extend_from_slice(b"VOX ");
+		out.extend_from_slice(&Self::VERSION.to_le_bytes());
+		push_chunk(&mut out, b"MAIN", &[], children.len());
+		out.extend_from_slice(&children);
+		out
+	}
+}
\ No newline at end of file
@@ -1,21 +1,43 @@
 use crate::collectable::{CollectableType, Collectable};
-use crate::chunk::Chunk;
+use crate::chunk::{ChunkData, Chunk};
+use crate::biome::{BiomeMap, Biome};
+use crate::cave::Cave;
+use crate::search::distance_squared;
+use crate::flood::{Adjacency, flood};
+use crate::inventory::Inventory;
+use crate::history::{History, Edit};
+use crate::chest::Chests;
+use crate::schematic::Schematic;
+use crate::transform::Transform;
+use crate::util::{WorldRect4, FillParams, Rect4};
 
+#[cfg(feature="serde")]
+use serde::{
+	de::{Unexpected, Error as DeserializationError},
+	Deserializer, Deserialize, Serializer, Serialize
+};
+#[cfg(feature="serde")]
 use serde_derive::{Deserialize, Serialize};
 
 use core::fmt::{Formatter, Display, Result as FmtResult};
 use std::collections::HashMap;
+#[cfg(feature="serde")]
+use std::collections::BTreeMap;
+use core::convert::{TryFrom, From};
+use std::collections::{hash_map, hash_set, HashSet};
 use core::default::Default;
 use core::cmp::PartialEq;
-use core::convert::Into;
+use core::mem::transmute;
+use core::{iter, slice};
 
 
 
 
 
 #[non_exhaustive]
-#[derive(Deserialize, PartialEq, Serialize, Clone, Debug, Copy, Hash, Eq)]
-#[serde(rename_all="snake_case")]
+#[derive(PartialEq, Clone, Debug, Copy, Hash, Eq)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature="serde", serde(rename_all="snake_case"))]
 #[repr(u8)]
 pub enum Block {
 	/// Air.
@@ -28,11 +50,9 @@ pub enum Block {
 	Stone,
 	/// Wood – a generic block to be used as a tree-trunk in non-Midnight biomes.
 	Wood,
-	///
 	Leaf,
 	/// Lava – a (purely decorative) placeholder block found at the bottom of the world.
 	Lava,
-	///
 	IronOre,
 	/// Deadly Ore – a glowing ore which produces the most valuable resource, Deadly Bars.
 	DeadlyOre,
@@ -40,17 +60,11 @@ pub enum Block {
 	Chest,
 	/// Midnight Grass – the Midnight biome's variant of [`Block::Grass`].
 	MidnightGrass,
-	///
 	MidnightSoil,
-	///
 	MidnightStone,
-	///
 	MidnightWood,
-	///
 	MidnightLeaf,
-	///
 	Bush,
-	///
 	MidnightBush,
 	/// A generic red flower.
 	RedFlower,
@@ -58,29 +72,22 @@ pub enum Block {
 	WhiteFlower,
 	/// A generic blue flower.
 	BlueFlower,
-	///
 	TallGrass,
 	/// Sand – a generic block of sand, used as the floor for the Desert biome.
 	Sand,
 	/// Sandstone – a generic block of (cobbled) sandstone which can be found under Sand.
 	Sandstone,
-	///
 	Cactus,
-	///
 	Snow,
-	///
 	Ice,
 	/// Snowy Bush – the Snow biome's variant of [`Block::Bush`].
 	SnowyBush,
 	/// Glass – a generic, see-through, block of glass crafted from Sand and Wood.
 	Glass,
-	///
 	SolenoidOre,
-	///
 	SnowyLeaf,
 	/// Pumpkin – a naturally, but infrequently, occurring block in grasslands.
 	Pumpkin,
-	///
 	JackOLantern,
 	/// Barrier – a special block which, presumably, represents an impassible block
 	Barrier,
@@ -89,11 +96,25 @@ pub enum Block {
 }
 
 impl Block {
+	/// Every block, in ID order.
+	pub const ALL:[Self; 34] = [
+		Self::Air, Self::Grass, Self::Dirt, Self::Stone, Self::Wood, Self::Leaf,
+		Self::Lava, Self::IronOre, Self::DeadlyOre, Self::Chest, Self::MidnightGrass, Self::MidnightSoil,
+		Self::MidnightStone, Self::MidnightWood, Self::MidnightLeaf, Self::Bush, Self::MidnightBush, Self::RedFlower,
+		Self::WhiteFlower, Self::BlueFlower, Self::TallGrass, Self::Sand, Self::Sandstone, Self::Cactus,
+		Self::Snow, Self::Ice, Self::SnowyBush, Self::Glass, Self::SolenoidOre, Self::SnowyLeaf,
+		Self::Pumpkin, Self::JackOLantern, Self::Barrier, Self::ChunkBorder
+	];
+	
+	/// Returns an iterator over every block, in ID order.
+	#[inline(always)] pub fn iter() -> iter::Copied<slice::Iter<'static, Self>> { Self::ALL.iter().copied() }
+	
 	#[inline(always)] pub const fn as_str(&self) -> &'static str {
 		match self {
 			Self::MidnightGrass => "Midnight Grass",
 			Self::MidnightStone => "Midnight Stone",
 			Self::JackOLantern  => "Jack o'Lantern",
+			Self::ChunkBorder   => "Chunk Border",
 			Self::MidnightBush  => "Midnight Bush",
 			Self::MidnightLeaf  => "Midnight Leaf",
 			Self::MidnightSoil  => "Midnight Soil",
@@ -123,16 +144,72 @@ impl Block {
 			Self::Snow          => "Snow",
 			Self::Wood          => "Wood",
 			Self::Air           => "Air",
-			Self::Ice           => "Ice",
-			
-			_ => ""
+			Self::Ice           => "Ice"
+		}
+	}
+	
+	/// Interprets the name of a block, ignoring case.
+	pub fn from_name(name:&str) -> Option<Self> { Self::iter().find(|block| name.eq_ignore_ascii_case(block.as_str())) }
+	
+	/// The type of collectable a block is, which is always [`CollectableType::Block`].
+	#[inline(always)] pub const fn typ(&self) -> CollectableType { CollectableType::Block }
+	
+	/// Whether this block is part of a plant or tree, rather than the ground.
+	pub const fn is_vegetation(&self) -> bool {
+		matches!(self,
+			Self::Wood | Self::Leaf | Self::MidnightWood | Self::MidnightLeaf | Self::SnowyLeaf |
+			Self::Bush | Self::MidnightBush | Self::SnowyBush | Self::TallGrass | Self::Cactus |
+			Self::RedFlower | Self::WhiteFlower | Self::BlueFlower | Self::Pumpkin | Self::JackOLantern
+		)
+	}
+	
+	/// Whether this block is an ore.
+	#[inline(always)] pub const fn is_ore(&self) -> bool { matches!(self, Self::IronOre | Self::DeadlyOre | Self::SolenoidOre) }
+	
+	/// The (RGBA) color used to represent this block outside of the game, such as in [`.vox`](crate::vox) models.
+	pub const fn color(&self) -> [u8; 4] {
+		match self {
+			Self::Air           => [  0,   0,   0,   0],
+			Self::Grass         => [ 92, 160,  60, 255],
+			Self::Dirt          => [121,  85,  58, 255],
+			Self::Stone         => [125, 125, 125, 255],
+			Self::Wood          => [102,  76,  51, 255],
+			Self::Leaf          => [ 58, 122,  36, 255],
+			Self::Lava          => [207,  76,  14, 255],
+			Self::IronOre       => [176, 140, 120, 255],
+			Self::DeadlyOre     => [ 70, 220, 200, 255],
+			Self::Chest         => [150, 105,  50, 255],
+			Self::MidnightGrass => [ 60,  40, 110, 255],
+			Self::MidnightSoil  => [ 45,  35,  70, 255],
+			Self::MidnightStone => [ 70,  70,  95, 255],
+			Self::MidnightWood  => [ 50,  40,  60, 255],
+			Self::MidnightLeaf  => [ 90,  60, 160, 255],
+			Self::Bush          => [ 75, 145,  45, 255],
+			Self::MidnightBush  => [110,  75, 180, 255],
+			Self::RedFlower     => [200,  40,  40, 255],
+			Self::WhiteFlower   => [235, 235, 235, 255],
+			Self::BlueFlower    => [ 60,  90, 220, 255],
+			Self::TallGrass     => [115, 175,  75, 255],
+			Self::Sand          => [220, 205, 150, 255],
+			Self::Sandstone     => [196, 172, 118, 255],
+			Self::Cactus        => [ 40, 110,  70, 255],
+			Self::Snow          => [245, 250, 255, 255],
+			Self::Ice           => [160, 200, 240, 255],
+			Self::SnowyBush     => [195, 220, 205, 255],
+			Self::Glass         => [200, 230, 240,  96],
+			Self::SolenoidOre   => [220,  60, 200, 255],
+			Self::SnowyLeaf     => [215, 235, 230, 255],
+			Self::Pumpkin       => [225, 130,  30, 255],
+			Self::JackOLantern  => [250, 180,  50, 255],
+			Self::Barrier       => [255,   0,   0,  64],
+			Self::ChunkBorder   => [255, 255,   0,  64]
 		}
 	}
 }
 
 impl Collectable for Block {
 	fn name(&self) -> &str { self.as_str() }
-	#[inline(always)] fn typ(&self)  -> CollectableType { CollectableType::Block }
+	#[inline(always)] fn typ(&self)  -> CollectableType { Block::typ(self) }
 }
 
 impl PartialEq<u8> for Block {
@@ -147,20 +224,548 @@ impl Display for Block {
 	fn fmt(&self, f:&mut Formatter) -> FmtResult { f.write_str(self.as_str()) }
 }
 
-impl Into<u8> for Block {
-	fn into(self) -> u8 { self as u8 }
+impl TryFrom<u8> for Block {
+	type Error = u8;
+	
+	/// Attempts to interpret a block ID as a [`Block`], giving back the ID if it is not recognized.
+	fn try_from(v:u8) -> Result<Self, Self::Error> {
+		if v <= Self::ChunkBorder as u8 { Ok(unsafe { transmute::<u8, Self>(v) }) } else { Err(v) }
+	}
+}
+
+impl From<Block> for u8 {
+	fn from(v:Block) -> Self { v as u8 }
 }
 
 
 
 /// A world.
-#[derive(PartialEq, Clone, Debug, Eq)]
-#[repr(transparent)]
-pub struct World(HashMap<(i64, i64, i64), Chunk>);
+/// 
+/// Chunks are keyed by their (X, Z, W) position in chunk-space,
+///  as every chunk spans the whole height of the world.  
+/// Blocks are addressed with (X, Y, Z, W) world-space coordinates.
+/// 
+/// Chunks which have been changed through the world are marked as dirty,
+///  so that only they need to be saved.
+/// 
+/// Edits made through the world (fills, pastes, replacements and set blocks) can be undone and redone,
+///  once it has been given a [history budget](World::set_history_budget).
+/// 
+/// The contents of the world's chests are kept alongside its chunks,
+///  and forgotten whenever an edit made through the world removes their chest (until that edit is undone).
+#[derive(Default, Clone, Debug)]
+pub struct World {
+	chunks:HashMap<(i64, i64, i64), Chunk>,
+	dirty:HashSet<(i64, i64, i64)>,
+	history:History,
+	chests:Chests
+}
 
 impl World {
 	/// The size of the (whole) world along the Y axis.
 	pub const HEIGHT:usize = 128;
+	
+	/// Creates a new [`World`] without any chunks.
+	#[inline(always)] pub fn new() -> Self { Self {chunks: HashMap::new(), dirty: HashSet::new(), history: History::default(), chests: Chests::new()} }
+	
+	/// Splits a world-space location into the key of the chunk containing it,
+	///  and the location of the block within that chunk.
+	/// 
+	/// Returns [`None`] if the location is above or below the world.
+	#[allow(clippy::type_complexity)]
+	pub const fn locate(loc:(i64, i64, i64, i64)) -> Option<((i64, i64, i64), (usize, usize, usize, usize))> {
+		if loc.1 < 0 || loc.1 >= Self::HEIGHT as i64 { return None; }
+		Some((
+			(loc.0.div_euclid(Chunk::WIDTH as i64), loc.2.div_euclid(Chunk::LENGTH as i64), loc.3.div_euclid(Chunk::WETH as i64)),
+			(
+				loc.0.rem_euclid(Chunk::WIDTH as i64) as usize,
+				loc.1 as usize,
+				loc.2.rem_euclid(Chunk::LENGTH as i64) as usize,
+				loc.3.rem_euclid(Chunk::WETH as i64) as usize
+			)
+		))
+	}
+	
+	/// Gets the chunk at the specified position in chunk-space, if it is loaded.
+	#[inline(always)] pub fn chunk(&self, key:(i64, i64, i64)) -> Option<&Chunk> { self.chunks.get(&key) }
+	
+	/// Mutably gets the chunk at the specified position in chunk-space, if it is loaded,
+	///  marking it as dirty.
+	/// 
+	/// As changes made to the chunk can not be recorded, this forgets the world's [`History`].  
+	/// Nor can they be checked for removed chests: the contents of any chest removed through the chunk
+	///  are kept until the next edit made through the world (or [`World::set_chests`]) forgets them.
+	pub fn chunk_mut(&mut self, key:(i64, i64, i64)) -> Option<&mut Chunk> {
+		let chunk = self.chunks.get_mut(&key)?;
+		self.dirty.insert(key);
+		self.history.clear();
+		Some(chunk)
+	}
+	
+	/// Inserts a (clean) chunk at the specified position in chunk-space, returning the chunk it replaced (if any).
+	/// 
+	/// If a chunk is replaced, this forgets the world's [`History`].  
+	/// The contents of any chest whose location does not hold a [`Block::Chest`] in the inserted chunk are forgotten.
+	pub fn insert_chunk(&mut self, key:(i64, i64, i64), chunk:Chunk) -> Option<Chunk> {
+		self.dirty.remove(&key);
+		let prev = self.chunks.insert(key, chunk);
+		if prev.is_some() { self.history.clear(); }
+		self.forget_removed_chests();
+		prev
+	}
+	
+	/// Removes the chunk at the specified position in chunk-space, returning it (if it was loaded).
+	/// 
+	/// If a chunk is removed, this forgets the world's [`History`].
+	pub fn remove_chunk(&mut self, key:(i64, i64, i64)) -> Option<Chunk> {
+		self.dirty.remove(&key);
+		let prev = self.chunks.remove(&key);
+		if prev.is_some() { self.history.clear(); }
+		prev
+	}
+	
+	/// Returns an iterator over every loaded chunk, alongside its position in chunk-space.
+	#[inline(always)] pub fn chunks(&self) -> hash_map::Iter<'_, (i64, i64, i64), Chunk> { self.chunks.iter() }
+	
+	/// Whether the chunk at the specified position in chunk-space has been changed since it was loaded.
+	#[inline(always)] pub fn is_dirty(&self, key:(i64, i64, i64)) -> bool { self.dirty.contains(&key) }
+	
+	/// Returns an iterator over the position (in chunk-space) of every dirty chunk.
+	#[inline(always)] pub fn dirty_chunks(&self) -> hash_set::Iter<'_, (i64, i64, i64)> { self.dirty.iter() }
+	
+	/// Marks every chunk as clean (such as after they have been saved).
+	#[inline(always)] pub fn clear_dirty(&mut self) { self.dirty.clear(); }
+	
+	/// The record of edits made to this world.
+	#[inline(always)] pub fn history(&self) -> &History { &self.history }
+	
+	/// Sets the number of bytes this world can use to remember edits, so that they can be undone,
+	///  forgetting any edits it already remembers.
+	/// 
+	/// A budget of `0` (the default) turns the history off.
+	pub fn set_history_budget(&mut self, budget:usize) { self.history = History::with_budget(budget); }
+	
+	/// Undoes the most recent edit that has not been undone, returning whether there was one.
+	/// 
+	/// Chunks which were created by the edit are removed, and chests which were removed by it are brought back with their contents.
+	pub fn undo(&mut self) -> bool {
+		let Some(mut edit) = self.history.pop_undo() else { return false; };
+		for change in &edit.chunks {
+			if change.created {
+				self.chunks.remove(&change.key);
+				self.dirty.remove(&change.key);
+				continue;
+			}
+			if let Some(chunk) = self.chunks.get_mut(&change.key) { chunk.apply_delta(&change.delta); }
+			self.dirty.insert(change.key);
+		}
+		self.swap_chests(&mut edit);
+		self.history.push_redo(edit);
+		true
+	}
+	
+	/// Redoes the most recently undone edit, returning whether there was one.
+	/// 
+	/// Any new edit forgets every edit which could be redone.
+	pub fn redo(&mut self) -> bool {
+		let Some(mut edit) = self.history.pop_redo() else { return false; };
+		for change in &edit.chunks {
+			self.chunks.entry(change.key).or_default().apply_delta(&change.delta);
+			self.dirty.insert(change.key);
+		}
+		self.swap_chests(&mut edit);
+		self.history.push_undo(edit);
+		true
+	}
+	
+	/// Runs `f` on this world, grouping every edit it makes into a single edit,
+	///  which is undone and redone as a whole.
+	pub fn batch<R, F:FnOnce(&mut Self)->R>(&mut self, f:F) -> R {
+		self.history.begin();
+		let out = f(self);
+		self.end_edit();
+		out
+	}
+	
+	/// The contents of every chest in this world.
+	#[inline(always)] pub fn chests(&self) -> &Chests { &self.chests }
+	
+	/// Replaces the contents of every chest in this world,
+	///  leaving out any chests whose location does not hold a [`Block::Chest`] in a loaded chunk.
+	pub fn set_chests(&mut self, chests:Chests) {
+		self.chests = chests;
+		self.forget_removed_chests();
+	}
+	
+	/// Gets the contents of the chest at a location in world-space, if there is one.
+	#[inline(always)] pub fn chest(&self, loc:(i64, i64, i64, i64)) -> Option<&Inventory> { self.chests.get(loc) }
+	
+	/// Mutably gets the contents of the chest at a location in world-space, if there is one.
+	/// 
+	/// A [`Block::Chest`] only has contents once they have been [placed](World::place_chest) or [set](World::set_chests).
+	#[inline(always)] pub fn chest_mut(&mut self, loc:(i64, i64, i64, i64)) -> Option<&mut Inventory> { self.chests.get_mut(loc) }
+	
+	/// Places a chest with the specified contents at a location in world-space, returning the contents of the chest it replaced (if any).
+	/// 
+	/// Returns [`None`] (and does nothing) if the location is above or below the world.
+	pub fn place_chest(&mut self, loc:(i64, i64, i64, i64), contents:Inventory) -> Option<Inventory> {
+		self.set_block(loc, Block::Chest as u8)?;
+		self.chests.insert(loc, contents)
+	}
+	
+	/// Finishes an edit (or a nested part of one), forgetting any chests it removed once the outermost edit is finished,
+	///  and keeping their contents in the history so that undoing the edit brings them back.
+	fn end_edit(&mut self) {
+		let removed = if self.history.is_outermost() { self.forget_removed_chests() } else { Vec::new() };
+		self.history.end(&self.chunks, removed);
+	}
+	
+	/// Forgets the chests removed by undoing or redoing an edit, and brings back those it holds, leaving it holding the ones just forgotten.
+	fn swap_chests(&mut self, edit:&mut Edit) {
+		let removed = self.forget_removed_chests();
+		for (loc, contents) in core::mem::replace(&mut edit.chests, removed) {
+			if self.may_hold_chest(loc) { self.chests.insert(loc, contents); }
+		}
+	}
+	
+	/// Whether a location in world-space holds a [`Block::Chest`], or is in a chunk which is not loaded.
+	fn may_hold_chest(&self, loc:(i64, i64, i64, i64)) -> bool {
+		match Self::locate(loc) {
+			Some((key, local)) => self.chunks.get(&key).is_none_or(|chunk| chunk.get_block(local) == Some(Block::Chest as u8)),
+			None               => false
+		}
+	}
+	
+	/// Forgets the contents of every chest whose location no longer holds a [`Block::Chest`] (in a loaded chunk), returning them.
+	fn forget_removed_chests(&mut self) -> Vec<((i64, i64, i64, i64), Inventory)> {
+		let removed = self.chests.iter().map(|(&loc, _)| loc).filter(|&loc| !self.may_hold_chest(loc)).collect::<Vec<_>>();
+		removed.into_iter().filter_map(|loc| Some((loc, self.chests.remove(loc)?))).collect()
+	}
+	
+	/// Gets the chunk at the specified position in chunk-space for editing,
+	///  creating a chunk filled with air if it is not loaded, and marking it as dirty.
+	pub(crate) fn touch(&mut self, key:(i64, i64, i64)) -> &mut Chunk {
+		self.history.record(key, self.chunks.get(&key));
+		self.dirty.insert(key);
+		self.chunks.entry(key).or_default()
+	}
+	
+	/// (Try to) Get the ID of a block at a given location in world-space.
+	/// 
+	/// Returns [`None`] if the location is outside of the world, or the chunk containing it is not loaded.
+	pub fn get_block(&self, loc:(i64, i64, i64, i64)) -> Option<u8> {
+		let (key, local) = Self::locate(loc)?;
+		self.chunks.get(&key)?.get_block(local)
+	}
+	
+	/// Sets the ID of a block at a given location in world-space, returning the ID of the block it replaced.
+	/// 
+	/// If the chunk containing the location is not loaded, a chunk filled with air is created in its place.  
+	/// Returns [`None`] (and does nothing) if the location is above or below the world.
+	pub fn set_block(&mut self, loc:(i64, i64, i64, i64), block_id:u8) -> Option<u8> {
+		let (key, local) = Self::locate(loc)?;
+		self.batch(|world| world.touch(key).set_block(local, block_id))
+	}
+	
+	/// Fills a region of the world, spanning any number of chunks, according to some [`FillParams`].
+	/// 
+	/// The area described by the parameters is placed with its origin, `(0, 0, 0, 0)`, at `origin`,
+	///  and locations passed to its determiner are relative to `origin`.  
+	/// Chunks which are touched by the fill are created if they are not loaded, and are marked as dirty.
+	pub fn fill_with_params(&mut self, origin:(i64, i64, i64, i64), mut fill_params:FillParams) {
+		self.batch(|world| for (key, local) in WorldRect4::from_rect(fill_params.rect).translate(origin).split_chunks() {
+			let base       = (key.0*Chunk::WIDTH as i64, 0, key.1*Chunk::LENGTH as i64, key.2*Chunk::WETH as i64);
+			let determiner = fill_params.determiner.as_mut();
+			world.touch(key).fill_rect(local, |(x, y, z, w), _| {
+				let p = (base.0 + x as i64, y as i64, base.2 + z as i64, base.3 + w as i64);
+				determiner(((p.0-origin.0) as usize, (p.1-origin.1) as usize, (p.2-origin.2) as usize, (p.3-origin.3) as usize), p)
+			});
+		});
+	}
+	
+	/// Fills a region of the world like [`World::fill_with_params`],
+	///  but only where `predicate` holds for the block that is already there.
+	/// 
+	/// Chunks which are not loaded are left out, and only chunks which are changed are marked as dirty.  
+	/// Returns the number of blocks that were changed.
+	pub fn fill_where<P:FnMut(u8)->bool>(&mut self, origin:(i64, i64, i64, i64), mut fill_params:FillParams, mut predicate:P) -> usize {
+		self.history.begin();
+		let mut changed = 0;
+		for (key, local) in WorldRect4::from_rect(fill_params.rect).translate(origin).split_chunks() {
+			let Some(chunk) = self.chunks.get_mut(&key) else { continue; };
+			self.history.record(key, Some(chunk));
+			
+			let base       = (key.0*Chunk::WIDTH as i64, 0, key.1*Chunk::LENGTH as i64, key.2*Chunk::WETH as i64);
+			let determiner = fill_params.determiner.as_mut();
+			let n = chunk.fill_rect(local, |(x, y, z, w), existing| {
+				if !predicate(existing) { return None; }
+				
+				let p = (base.0 + x as i64, y as i64, base.2 + z as i64, base.3 + w as i64);
+				determiner(((p.0-origin.0) as usize, (p.1-origin.1) as usize, (p.2-origin.2) as usize, (p.3-origin.3) as usize), p)
+			});
+			
+			if n > 0 { self.dirty.insert(key); }
+			changed += n;
+		}
+		self.end_edit();
+		changed
+	}
+	
+	/// Replaces every block of one ID in every loaded chunk with a block of another, returning the number of blocks that were changed.
+	pub fn replace(&mut self, from:u8, to:u8) -> usize {
+		if from == to { return 0; }
+		
+		self.history.begin();
+		let mut changed = 0;
+		for (&key, chunk) in self.chunks.iter_mut() {
+			if !chunk.contains(from) { continue; }
+			self.history.record(key, Some(chunk));
+			
+			let n = chunk.replace(from, to);
+			if n > 0 { self.dirty.insert(key); }
+			changed += n;
+		}
+		self.end_edit();
+		changed
+	}
+	
+	/// Fills a region of the world, spanning any number of chunks, with a block of the specified ID.
+	pub fn fill(&mut self, block_id:u8, rect:WorldRect4) {
+		self.fill_with_params(rect.normalized().start, FillParams::solid(block_id, Rect4::with_size((0, 0, 0, 0), rect.size())));
+	}
+	
+	/// Finds every block of the specified ID within a region of the world,
+	///  sorted by their (4D) distance from `from`, nearest first.
+	/// 
+	/// Only the part of each loaded chunk which the region overlaps is searched, and only if the chunk holds the block at all.  
+	/// See [`search::veins`](crate::search::veins) to cluster the blocks that are found.
+	pub fn find_blocks(&self, block_id:u8, rect:WorldRect4, from:(i64, i64, i64, i64)) -> Vec<(i64, i64, i64, i64)> {
+		let rect = rect.normalized();
+		
+		let mut found = Vec::new();
+		for (&key, chunk) in &self.chunks {
+			let base   = (key.0*Chunk::WIDTH as i64, 0, key.1*Chunk::LENGTH as i64, key.2*Chunk::WETH as i64);
+			let bounds = WorldRect4::with_size(base, (Chunk::WIDTH, Self::HEIGHT, Chunk::LENGTH, Chunk::WETH));
+			let Some(overlap) = bounds.intersection(&rect) else { continue; };
+			if !chunk.contains(block_id) { continue; }
+			
+			let local = Rect4::new(
+				((overlap.start.0-base.0) as usize, overlap.start.1 as usize, (overlap.start.2-base.2) as usize, (overlap.start.3-base.3) as usize),
+				((overlap.end.0-base.0) as usize, overlap.end.1 as usize, (overlap.end.2-base.2) as usize, (overlap.end.3-base.3) as usize)
+			);
+			found.extend(
+				local.iter()
+					.filter(|&loc| chunk.get_block(loc) == Some(block_id))
+					.map(|(x, y, z, w)| (base.0 + x as i64, y as i64, base.2 + z as i64, base.3 + w as i64))
+			);
+		}
+		found.sort_unstable_by_key(|&loc| (distance_squared(loc, from), loc));
+		found
+	}
+	
+	/// Finds every block of the specified ID within `radius` blocks of `center`, sorted by their (4D) distance from it, nearest first.
+	pub fn find_blocks_near(&self, block_id:u8, center:(i64, i64, i64, i64), radius:f64) -> Vec<(i64, i64, i64, i64)> {
+		let r    = radius.max(0.0).floor() as i64;
+		let rect = WorldRect4::new(center, center).expand(r);
+		
+		let mut found = self.find_blocks(block_id, rect, center);
+		found.retain(|&loc| distance_squared(loc, center) as f64 <= radius*radius);
+		found
+	}
+	
+	/// Finds every block connected to the block at `start` through blocks within `within` for which `predicate` holds,
+	///  in the order they are reached.
+	/// 
+	/// Blocks in chunks which are not loaded are never connected.  
+	/// Returns nothing if `predicate` does not hold for the block at `start`, or it is outside of `within`.
+	pub fn connected<P:FnMut(u8)->bool>(&self, start:(i64, i64, i64, i64), within:WorldRect4, adjacency:Adjacency, mut predicate:P) -> Vec<(i64, i64, i64, i64)> {
+		let within = within.normalized();
+		flood(start, adjacency, |loc| within.contains(loc) && self.get_block(loc).is_some_and(&mut predicate), &mut HashSet::new())
+	}
+	
+	/// Splits every block within `within` for which `predicate` holds into groups of blocks which are connected to each other.
+	/// 
+	/// Blocks in chunks which are not loaded are left out, and never connect blocks to each other.
+	pub fn components<P:FnMut(u8)->bool>(&self, within:WorldRect4, adjacency:Adjacency, mut predicate:P) -> Vec<Vec<(i64, i64, i64, i64)>> {
+		let within = within.normalized();
+		
+		let mut seen       = HashSet::new();
+		let mut components = Vec::new();
+		for (key, local) in within.split_chunks() {
+			if !self.chunks.contains_key(&key) { continue; }
+			
+			let base = (key.0*Chunk::WIDTH as i64, 0, key.1*Chunk::LENGTH as i64, key.2*Chunk::WETH as i64);
+			for (x, y, z, w) in local.iter() {
+				let start = (base.0 + x as i64, y as i64, base.2 + z as i64, base.3 + w as i64);
+				let component = flood(start, adjacency, |loc| within.contains(loc) && self.get_block(loc).is_some_and(&mut predicate), &mut seen);
+				if !component.is_empty() { components.push(component); }
+			}
+		}
+		components
+	}
+	
+	/// Finds groups of (non-air) blocks within `within` which are not connected to the bottom of the world.
+	/// 
+	/// Groups which touch the border of `within` may be connected to blocks outside of it, so they are never counted as floating.
+	pub fn floating_blocks(&self, within:WorldRect4, adjacency:Adjacency) -> Vec<Vec<(i64, i64, i64, i64)>> {
+		let within = within.normalized();
+		let (bottom, top) = (within.start.1.max(0), within.end.1.min(Self::HEIGHT as i64-1));
+		let inside = |loc:(i64, i64, i64, i64)| {
+			loc.0 > within.start.0 && loc.0 < within.end.0 &&
+			loc.2 > within.start.2 && loc.2 < within.end.2 &&
+			loc.3 > within.start.3 && loc.3 < within.end.3 &&
+			loc.1 > bottom && (loc.1 < top || top == Self::HEIGHT as i64-1)
+		};
+		
+		let mut floating = self.components(within, adjacency, |id| id != Block::Air as u8);
+		floating.retain(|component| component.iter().all(|&loc| inside(loc)));
+		floating
+	}
+	
+	/// Replaces the block at `start`, and every block of the same ID connected to it within `within`, with a block of the specified ID.
+	/// 
+	/// Returns the number of blocks that were changed.
+	pub fn bucket_fill(&mut self, start:(i64, i64, i64, i64), block_id:u8, within:WorldRect4, adjacency:Adjacency) -> usize {
+		let Some(from) = self.get_block(start) else { return 0; };
+		if from == block_id { return 0; }
+		
+		let region = self.connected(start, within, adjacency, |id| id == from);
+		self.batch(|world| for &loc in &region { world.set_block(loc, block_id); });
+		region.len()
+	}
+	
+	/// Finds the height of the ground of the column of the world at an (X, Z, W) location:
+	///  its highest block which is neither air nor part of a plant or tree.
+	/// 
+	/// Returns [`None`] if the column is empty, or the chunk containing it is not loaded.
+	pub fn ground_height(&self, (x, z, w):(i64, i64, i64)) -> Option<i64> {
+		let (key, (x, _, z, w)) = Self::locate((x, 0, z, w))?;
+		let chunk = self.chunks.get(&key)?;
+		(0..Self::HEIGHT).rev()
+			.find(|&y| chunk.get_block((x, y, z, w)).and_then(|id| Block::try_from(id).ok()).is_none_or(|block| block != Block::Air && !block.is_vegetation()))
+			.map(|y| y as i64)
+	}
+	
+	/// Finds the caves within a region of the world; see [`Cave`] for what counts as one.
+	#[inline(always)] pub fn caves(&self, within:WorldRect4) -> Vec<Cave> { Cave::find(self, within) }
+	
+	/// Classifies the column of the world at an (X, Z, W) location into a [`Biome`], by its surface.
+	/// 
+	/// Returns [`None`] if the column could not be classified, or the chunk containing it is not loaded.
+	pub fn biome_at(&self, (x, z, w):(i64, i64, i64)) -> Option<Biome> {
+		let (key, (x, _, z, w)) = Self::locate((x, 0, z, w))?;
+		Biome::of_column(self.chunks.get(&key)?, (x, z, w))
+	}
+	
+	/// Classifies every column of the chunk at the specified position in chunk-space, if it is loaded.
+	#[inline(always)] pub fn biome_map(&self, key:(i64, i64, i64)) -> Option<BiomeMap> { self.chunks.get(&key).map(BiomeMap::of_chunk) }
+	
+	/// Returns an iterator over the [`BiomeMap`] of every loaded chunk, alongside its position in chunk-space.
+	pub fn biome_maps(&self) -> impl Iterator<Item=((i64, i64, i64), BiomeMap)> + '_ {
+		self.chunks.iter().map(|(&key, chunk)| (key, BiomeMap::of_chunk(chunk)))
+	}
+	
+	/// Copies every block in a region of the world into a [`Schematic`].
+	/// 
+	/// Blocks outside of the world, or in chunks that are not loaded, are copied as air.
+	pub fn extract(&self, rect:WorldRect4) -> Schematic {
+		Schematic::new(rect.size(), rect.iter().map(|loc| self.get_block(loc).unwrap_or(0)))
+	}
+	
+	/// Pastes every block in a [`Schematic`] into the world, with the schematic's first corner at `origin`.
+	/// 
+	/// Blocks which would be placed above or below the world are skipped.
+	pub fn paste(&mut self, schematic:&Schematic, origin:(i64, i64, i64, i64)) {
+		self.batch(|world| for (loc, block_id) in schematic.iter() {
+			world.set_block((origin.0+loc.0 as i64, origin.1+loc.1 as i64, origin.2+loc.2 as i64, origin.3+loc.3 as i64), block_id);
+		});
+	}
+	
+	/// Pastes a rotated and/or reflected copy of a [`Schematic`] into the world,
+	///  with the transformed schematic's first corner at `origin`.
+	/// 
+	/// Returns the area of the world that was pasted over.
+	pub fn paste_transformed(&mut self, schematic:&Schematic, origin:(i64, i64, i64, i64), transform:Transform) -> WorldRect4 {
+		let size = schematic.size();
+		self.batch(|world| for (loc, block_id) in schematic.iter() {
+			let loc = transform.apply(loc, size);
+			world.set_block((origin.0+loc.0 as i64, origin.1+loc.1 as i64, origin.2+loc.2 as i64, origin.3+loc.3 as i64), block_id);
+		});
+		transform.apply_world_rect(schematic.bounds(origin))
+	}
+}
+
+impl PartialEq for World {
+	/// Compares the chunks and chests of two worlds, regardless of which of them are dirty.
+	fn eq(&self, other:&Self) -> bool { self.chunks == other.chunks && self.chests == other.chests }
+}
+
+impl Eq for World {}
+
+/// Serializes the world's chunks, keyed by their position in chunk-space, alongside the contents of its chests.
+/// 
+/// Neither the world's [`History`] nor which of its chunks are dirty is serialized.
+#[cfg(feature="serde")]
+impl Serialize for World {
+	fn serialize<S:Serializer>(&self, s:S) -> Result<S::Ok, S::Error> {
+		let mut chests = self.chests.iter().map(|(loc, contents)| (*loc, contents)).collect::<Vec<_>>();
+		chests.sort_unstable_by_key(|(loc, _)| *loc);
+		
+		WorldData {
+			chunks: self.chunks.iter().map(|(key, chunk)| (ChunkKey(*key), chunk)).collect::<BTreeMap<_, _>>(),
+			chests
+		}.serialize(s)
+	}
+}
+
+#[cfg(feature="serde")]
+impl<'de> Deserialize<'de> for World {
+	fn deserialize<D:Deserializer<'de>>(d:D) -> Result<Self, D::Error> {
+		let data = WorldData::<BTreeMap<ChunkKey, Chunk>, Vec<((i64, i64, i64, i64), Inventory)>>::deserialize(d)?;
+		let mut world = Self::new();
+		
+		world.chunks.extend(data.chunks.into_iter().map(|(key, chunk)| (key.0, chunk)));
+		world.set_chests(data.chests.into_iter().collect());
+		Ok(world)
+	}
+}
+
+
+
+/// The serialized form of a [`World`].
+#[cfg(feature="serde")]
+#[derive(Deserialize, Serialize)]
+struct WorldData<C, I> {
+	chunks:C,
+	chests:I
+}
+
+/// The position of a chunk in chunk-space, which serializes as `"x,z,w"` in human-readable formats
+///  (so that it can be used as a key in formats such as JSON), and as a tuple otherwise.
+#[cfg(feature="serde")]
+#[derive(PartialOrd, PartialEq, Clone, Copy, Ord, Eq)]
+struct ChunkKey((i64, i64, i64));
+
+#[cfg(feature="serde")]
+impl Serialize for ChunkKey {
+	fn serialize<S:Serializer>(&self, s:S) -> Result<S::Ok, S::Error> {
+		let (x, z, w) = self.0;
+		if s.is_human_readable() { s.collect_str(&format_args!("{x},{z},{w}")) } else { self.0.serialize(s) }
+	}
+}
+
+#[cfg(feature="serde")]
+impl<'de> Deserialize<'de> for ChunkKey {
+	fn deserialize<D:Deserializer<'de>>(d:D) -> Result<Self, D::Error> {
+		if !d.is_human_readable() { return Ok(Self(<(i64, i64, i64)>::deserialize(d)?)); }
+		
+		let key    = String::deserialize(d)?;
+		let coords = key.split(',').map(|c| c.trim().parse::<i64>()).collect::<Result<Vec<_>, _>>();
+		match coords.as_deref() {
+			Ok(&[x, z, w]) => Ok(Self((x, z, w))),
+			_ => Err(D::Error::invalid_value(Unexpected::Str(&key), &"the position of a chunk, as \"x,z,w\""))
+		}
+	}
 }
 
 
@@ -0,0 +1,142 @@
+use crate::noise::{fractal4, value4, unit4};
+use crate::world::{Block, World};
+use crate::chunk::Chunk;
+use crate::biome::Biome;
+use crate::util::Rect4;
+
+use core::iter::IntoIterator;
+use core::default::Default;
+
+
+
+
+
+/// A seeded generator of terrain resembling that of 4D Miner worlds.
+/// 
+/// Every block is decided by the seed and its location alone,
+///  so a chunk is always generated the same way, regardless of which (or how many) other chunks are generated.
+/// 
+/// Each column of the world is made up of (from the bottom up):
+///  a layer of [`Block::Lava`], the biome's [stone](Biome::stone) (with ore veins),
+///  a few layers of its [subsurface](Biome::subsurface) and a layer of its [surface](Biome::surface).  
+/// Snow biomes are frozen over with [`Block::Ice`] up to the sea level, and deserts are dotted with [`Block::Cactus`].
+#[derive(PartialEq, Clone, Debug, Copy)]
+pub struct TerrainGenerator {
+	/// The seed that every random decision is derived from.
+	pub seed:u64,
+	/// The lowest height of the surface.
+	pub base_height:usize,
+	/// How far the surface can rise above `base_height`.
+	pub height_variation:usize,
+	/// Roughly how many blocks apart hills are.
+	pub terrain_scale:f64,
+	/// Roughly how many blocks apart biomes are.
+	pub biome_scale:f64,
+	/// The height that low ground in snow biomes is frozen over up to.
+	pub sea_level:usize
+}
+
+impl TerrainGenerator {
+	/// How many layers of a biome's [subsurface](Biome::subsurface) block are below its surface.
+	pub const SUBSURFACE_DEPTH:usize = 3;
+	
+	/// Creates a new [`TerrainGenerator`] with the specified seed, and otherwise default settings.
+	#[inline(always)] pub const fn new(seed:u64) -> Self {
+		Self {seed, base_height: 40, height_variation: 24, terrain_scale: 48.0, biome_scale: 192.0, sea_level: 48}
+	}
+	
+	/// Decides the biome of the column of the world at an (X, Z, W) location.
+	pub fn biome_at(&self, (x, z, w):(i64, i64, i64)) -> Biome {
+		let p = (x as f64 / self.biome_scale, z as f64 / self.biome_scale, w as f64 / self.biome_scale, 0.0);
+		if value4(self.seed ^ SEED_MIDNIGHT, p) > 0.7 { return Biome::Midnight; }
+		
+		match value4(self.seed ^ SEED_TEMPERATURE, p) {
+			t if t < 0.3 => Biome::Snow,
+			t if t > 0.7 => Biome::Desert,
+			_            => Biome::Grassland
+		}
+	}
+	
+	/// Decides the height of the surface of the column of the world at an (X, Z, W) location.
+	pub fn height_at(&self, (x, z, w):(i64, i64, i64)) -> usize {
+		let p = (x as f64 / self.terrain_scale, z as f64 / self.terrain_scale, w as f64 / self.terrain_scale, 0.0);
+		let h = self.base_height + (fractal4(self.seed ^ SEED_HEIGHT, p, 4) * self.height_variation as f64) as usize;
+		h.clamp(1, World::HEIGHT-1)
+	}
+	
+	/// Decides which ore (if any) replaces the stone at a location in world-space.
+	/// 
+	/// Rarer ores are only found deeper in the world.
+	pub fn ore_at(&self, (x, y, z, w):(i64, i64, i64, i64)) -> Option<Block> {
+		const ORES:[(Block, i64, f64, u64); 3] = [
+			(Block::SolenoidOre, 12, 0.86, SEED_SOLENOID),
+			(Block::DeadlyOre,   24, 0.83, SEED_DEADLY),
+			(Block::IronOre,     64, 0.78, SEED_IRON)
+		];
+		
+		let p = (x as f64 / 3.0, y as f64 / 3.0, z as f64 / 3.0, w as f64 / 3.0);
+		ORES.iter().find(|&&(_, depth, threshold, seed)| y < depth && value4(self.seed ^ seed, p) > threshold).map(|&(ore, ..)| ore)
+	}
+	
+	/// Generates the chunk at the specified position in chunk-space.
+	pub fn generate_chunk(&self, key:(i64, i64, i64)) -> Chunk {
+		let base = (key.0*Chunk::WIDTH as i64, key.1*Chunk::LENGTH as i64, key.2*Chunk::WETH as i64);
+		
+		let mut columns = [[[(Biome::Grassland, 0, 0); Chunk::WETH]; Chunk::LENGTH]; Chunk::WIDTH];
+		for (x, column) in columns.iter_mut().enumerate() {
+			for (z, column) in column.iter_mut().enumerate() {
+				for (w, column) in column.iter_mut().enumerate() {
+					let at     = (base.0 + x as i64, base.1 + z as i64, base.2 + w as i64);
+					let biome  = self.biome_at(at);
+					let height = self.height_at(at);
+					let cactus = match biome {
+						Biome::Desert if unit4(self.seed ^ SEED_CACTUS, (at.0, 0, at.1, at.2)) < 0.01 => {
+							1 + (unit4(self.seed ^ SEED_CACTUS, (at.0, 1, at.1, at.2)) * 3.0) as usize
+						}
+						_ => 0
+					};
+					*column = (biome, height, cactus);
+				}
+			}
+		}
+		
+		let mut chunk = Chunk::filled_with(Block::Air as u8);
+		chunk.fill_rect(Rect4::new((0, 0, 0, 0), (Chunk::WIDTH-1, World::HEIGHT-1, Chunk::LENGTH-1, Chunk::WETH-1)), |(x, y, z, w), _| {
+			let (biome, height, cactus) = columns[x][z][w];
+			let block = match y {
+				0                                                    => Block::Lava,
+				y if y < height-Self::SUBSURFACE_DEPTH.min(height-1) => {
+					self.ore_at((base.0 + x as i64, y as i64, base.1 + z as i64, base.2 + w as i64)).unwrap_or(biome.stone())
+				}
+				y if y < height                                      => biome.subsurface(),
+				y if y == height                                     => biome.surface(),
+				y if biome == Biome::Snow && y <= self.sea_level     => Block::Ice,
+				y if y <= height+cactus                              => Block::Cactus,
+				_                                                    => return None
+			};
+			Some(block as u8)
+		});
+		chunk
+	}
+	
+	/// Generates every chunk at the specified positions in chunk-space, inserting them into a world.
+	pub fn generate_into<I:IntoIterator<Item=(i64, i64, i64)>>(&self, world:&mut World, keys:I) {
+		for key in keys { world.insert_chunk(key, self.generate_chunk(key)); }
+	}
+}
+
+impl Default for TerrainGenerator {
+	#[inline(always)] fn default() -> Self { Self::new(0) }
+}
+
+
+
+
+
+const SEED_TEMPERATURE:u64 = 0x7E3A_11C4_0B5D_92F1;
+const SEED_MIDNIGHT:u64    = 0x4D1D_0E5F_83A7_26C9;
+const SEED_SOLENOID:u64    = 0x50E_0105_7C2B_E48D;
+const SEED_HEIGHT:u64      = 0x1E16_47F0_99D3_A5B2;
+const SEED_CACTUS:u64      = 0xCAC7_0058_6E21_F3D4;
+const SEED_DEADLY:u64      = 0xDEAD_1E00_3F8C_B617;
+const SEED_IRON:u64        = 0x1205_0AE7_5C64_D98A;
\ No newline at end of file
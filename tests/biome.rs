@@ -0,0 +1,52 @@
+use fdm_toolkit::world::{Block, World};
+use fdm_toolkit::worldgen::TerrainGenerator;
+use fdm_toolkit::vegetation::decorate;
+use fdm_toolkit::util::WorldRect4;
+use fdm_toolkit::biome::Biome;
+
+
+
+
+
+/// Tests to see if the biomes of generated (and decorated) terrain are detected as the biomes it was generated with.
+#[test] fn detect_generated_biomes() {
+	let generator = TerrainGenerator::new(3);
+	let mut world = World::new();
+	let keys      = (-2..2).flat_map(|x| (-2..2).map(move |w| (x*16, 0, w*16)));
+	generator.generate_into(&mut world, keys);
+	decorate(&mut world, 9, WorldRect4::new((0, 0, 0, 0), (7, 127, 7, 7)), 0.05);
+	
+	let mut found = Vec::new();
+	for (key, map) in world.biome_maps() {
+		for ((x, z, w), biome) in map.iter() {
+			let at = (key.0*8 + x as i64, key.1*8 + z as i64, key.2*8 + w as i64);
+			assert_eq!(biome, Some(generator.biome_at(at)), "{at:?}");
+		}
+		if let Some(biome) = map.dominant() && !found.contains(&biome) { found.push(biome); }
+	}
+	assert!(found.len() > 1);
+	assert_eq!(world.biome_at((0, 0, 0)), Some(generator.biome_at((0, 0, 0))));
+	assert_eq!(world.biome_at((0, 0, 8)), None);
+}
+
+
+
+/// Tests to see if columns are classified by their ground, looking through plants and ice.
+#[test] fn classify_columns() {
+	let mut world = World::new();
+	world.fill(Block::Stone as u8, WorldRect4::new((0, 0, 0, 0), (7, 9, 7, 7)));
+	world.fill(Block::Sand as u8, WorldRect4::new((0, 10, 0, 0), (3, 10, 7, 7)));
+	world.fill(Block::Snow as u8, WorldRect4::new((4, 10, 0, 0), (7, 10, 7, 7)));
+	world.fill(Block::Ice as u8, WorldRect4::new((6, 11, 0, 0), (7, 12, 7, 7)));
+	world.fill(Block::Cactus as u8, WorldRect4::new((0, 11, 0, 0), (0, 13, 0, 0)));
+	world.set_block((0, 10, 1, 0), Block::Stone as u8);
+	
+	let map = world.biome_map((0, 0, 0)).unwrap();
+	assert_eq!(map.get((0, 0, 0)), Some(Biome::Desert));
+	assert_eq!(map.get((0, 1, 0)), None);
+	assert_eq!(map.get((7, 0, 0)), Some(Biome::Snow));
+	assert_eq!(map.count(Biome::Desert), 4*8*8-1);
+	assert_eq!(map.count(Biome::Snow), 4*8*8);
+	assert_eq!(map.dominant(), Some(Biome::Snow));
+	assert_eq!(map.get((8, 0, 0)), None);
+}
\ No newline at end of file
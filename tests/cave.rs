@@ -0,0 +1,43 @@
+use fdm_toolkit::world::{Block, World};
+use fdm_toolkit::util::WorldRect4;
+
+
+
+
+
+/// Tests to see if enclosed caves are found, measured and masked, and shafts open to the sky are left out.
+#[test] fn find_caves() {
+	let mut world = World::new();
+	let area = WorldRect4::new((-8, 0, -8, -8), (7, 31, 7, 7));
+	world.fill(Block::Stone as u8, WorldRect4::new((-8, 0, -8, -8), (7, 19, 7, 7)));
+	world.fill(Block::Air as u8, WorldRect4::new((-8, 20, -8, -8), (7, 31, 7, 7)));
+	world.fill(Block::Leaf as u8, WorldRect4::new((-8, 25, -8, -8), (7, 25, 7, 7)));
+	
+	world.fill(Block::Air as u8, WorldRect4::new((-3, 5, -1, -1), (4, 6, 1, 1)));
+	world.set_block((-4, 5, 0, 0), Block::DeadlyOre as u8);
+	world.set_block((0, 4, 0, 0), Block::IronOre as u8);
+	world.set_block((0, 4, 1, 0), Block::IronOre as u8);
+	world.fill(Block::Air as u8, WorldRect4::new((5, 12, 5, 5), (5, 19, 5, 5)));
+	world.fill(Block::Air as u8, WorldRect4::new((5, 12, 4, 5), (5, 12, 4, 5)));
+	
+	let caves = world.caves(area);
+	assert_eq!(caves.len(), 2);
+	
+	let cave = caves.iter().find(|cave| cave.volume() > 1).unwrap();
+	assert!(cave.enclosed);
+	assert_eq!(cave.volume(), 8*2*3*3);
+	assert_eq!(cave.bounds, WorldRect4::new((-3, 5, -1, -1), (4, 6, 1, 1)));
+	assert_eq!(cave.exposed_count(Block::DeadlyOre as u8), 1);
+	assert_eq!(cave.exposed_ores().map(|(_, n)| n).sum::<usize>(), 3);
+	
+	let mask = cave.mask(Block::Barrier as u8);
+	assert_eq!(mask.size(), (8, 2, 3, 3));
+	assert!(mask.blocks().all(|id| id == Block::Barrier as u8));
+	
+	// The bottom of the shaft is below the ground of the column next to it, but not its own.
+	let nook = caves.iter().find(|cave| cave.volume() == 1).unwrap();
+	assert_eq!(nook.blocks, [(5, 12, 4, 5)]);
+	assert!(!nook.enclosed);
+	
+	assert!(world.caves(WorldRect4::new((0, 0, -8, -8), (7, 31, 7, 7))).iter().all(|cave| !cave.enclosed));
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+use fdm_toolkit::inventory::{ItemStack, Inventory};
+use fdm_toolkit::util::{WorldRect4, FillParams, Rect4};
+use fdm_toolkit::world::{Block, World};
+use fdm_toolkit::collectable::Item;
+use fdm_toolkit::chest::Chests;
+
+
+
+
+
+/// Tests to see if chests are forgotten when world edits remove their block, and only then,
+///  and brought back when those edits are undone.
+#[test] fn chests_follow_edits() {
+	let mut world = World::new();
+	world.set_history_budget(1 << 20);
+	world.fill(Block::Stone as u8, WorldRect4::new((0, 0, 0, 0), (7, 3, 7, 7)));
+	
+	let mut contents = Inventory::new(27);
+	contents.add(ItemStack::new(Item::DeadlyBars, 5));
+	assert_eq!(world.place_chest((1, 4, 1, 1), contents.clone()), None);
+	world.place_chest((2, 4, 2, 2), Inventory::new(27));
+	world.set_block((3, 4, 3, 3), Block::Chest as u8);
+	
+	assert_eq!(world.chest((1, 4, 1, 1)), Some(&contents));
+	assert!(world.chest_mut((3, 4, 3, 3)).is_none());
+	world.place_chest((3, 4, 3, 3), Inventory::new(9));
+	world.chest_mut((3, 4, 3, 3)).unwrap().add(ItemStack::new(Block::Sand, 3));
+	assert!(world.chest_mut((4, 4, 4, 4)).is_none());
+	assert_eq!(world.chests().len(), 3);
+	
+	world.fill_where((0, 4, 0, 0), FillParams::solid(Block::Air as u8, Rect4::new((0, 0, 0, 0), (2, 0, 2, 2))), |_| true);
+	assert_eq!(world.chests().len(), 1);
+	assert_eq!(world.chest((3, 4, 3, 3)).unwrap().count(Block::Sand), 3);
+	world.replace(Block::Chest as u8, Block::Glass as u8);
+	assert!(world.chests().is_empty());
+	
+	// Undoing an edit brings back the chest with its contents, and redoing it forgets them again.
+	assert!(world.undo());
+	assert_eq!(world.get_block((3, 4, 3, 3)), Some(Block::Chest as u8));
+	assert_eq!(world.chest((3, 4, 3, 3)).unwrap().count(Block::Sand), 3);
+	assert!(world.redo());
+	assert!(world.chests().is_empty());
+	assert!(world.undo());
+	assert!(world.undo());
+	assert_eq!(world.chests().len(), 3);
+	assert_eq!(world.chest((1, 4, 1, 1)), Some(&contents));
+}
+
+
+
+/// Tests to see if chests can be collected and set on a world, keeping only those on a chest block.
+#[test] fn chests_set_on_worlds() {
+	let mut contents = Inventory::new(5);
+	contents.set(4, Some(ItemStack::new(Block::JackOLantern, 64)));
+	let chests = [((-100, 20, 7, -3), contents.clone()), ((1, 2, 3, 4), Inventory::new(1)), ((9, 9, 9, 9), Inventory::new(1))].into_iter().collect::<Chests>();
+	assert_eq!(chests.len(), 3);
+	
+	let mut world = World::new();
+	world.set_block((1, 2, 3, 4), Block::Chest as u8);
+	world.set_block((-100, 20, 7, -3), Block::Chest as u8);
+	world.set_block((9, 9, 9, 9), Block::Stone as u8);
+	world.set_chests(chests);
+	assert_eq!(world.chests().len(), 2);
+	assert_eq!(world.chest((-100, 20, 7, -3)), Some(&contents));
+	world.set_block((-100, 20, 7, -3), Block::Stone as u8);
+	assert_eq!(world.chests().len(), 1);
+}
\ No newline at end of file
@@ -39,6 +39,7 @@ use std::vec::Vec;
 		bytes = compressed.iter_bytes().collect();
 	}
 	
+	#[allow(clippy::collapsible_if)]
 	if !bytes.is_empty() {
 		if let Ok(mut out_chunk) = File::options().truncate(true).create(true).write(true).open("test_reexported_chunk.bin") {
 			_ = out_chunk.write_all(&bytes);
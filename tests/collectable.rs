@@ -0,0 +1,42 @@
+use fdm_toolkit::collectable::{AnyCollectable, CollectableType, Item};
+use fdm_toolkit::world::Block;
+
+use std::collections::HashSet;
+
+
+
+
+
+const IRON_PICK_TYPE:CollectableType = Item::IronPick.typ();
+const STONE_IS_ORE:bool = Block::Stone.is_ore();
+
+
+
+/// Tests to see if every block and item is listed in order, with a unique name.
+#[test] fn every_variant_listed() {
+	assert_eq!((IRON_PICK_TYPE, STONE_IS_ORE), (CollectableType::Tool, false));
+	
+	let blocks = (0..=u8::MAX).map_while(|id| Block::try_from(id).ok()).collect::<Vec<_>>();
+	let items  = (0..=u8::MAX).map_while(|idx| Item::try_from(idx).ok()).collect::<Vec<_>>();
+	assert_eq!(Block::iter().collect::<Vec<_>>(), blocks);
+	assert_eq!(Item::iter().collect::<Vec<_>>(), items);
+	assert!(Block::ALL.iter().all(|block| block.typ() == CollectableType::Block));
+	
+	let names = Block::iter().map(AnyCollectable::from).chain(Item::iter().map(AnyCollectable::from)).map(|c| c.as_str()).collect::<Vec<_>>();
+	assert!(names.iter().all(|name| !name.is_empty()));
+	assert_eq!(names.iter().collect::<HashSet<_>>().len(), names.len());
+	assert_eq!(Block::ChunkBorder.as_str(), "Chunk Border");
+}
+
+/// Tests to see if every block and item survives a serde round trip.
+#[cfg(feature="serde")]
+#[test] fn every_variant_round_trips() {
+	for block in Block::iter() {
+		assert_eq!(serde_json::from_str::<Block>(&serde_json::to_string(&block).unwrap()).unwrap(), block);
+	}
+	for item in Item::iter() {
+		let collectable = AnyCollectable::Item(item);
+		assert_eq!(serde_json::from_str::<Item>(&serde_json::to_string(&item).unwrap()).unwrap(), item);
+		assert_eq!(serde_json::from_str::<AnyCollectable>(&serde_json::to_string(&collectable).unwrap()).unwrap(), collectable);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use fdm_toolkit::chunk::{CompressedChunk, ChunkData, Chunk};
+use fdm_toolkit::err::ChunkDumpError;
+use fdm_toolkit::world::Block;
+use fdm_toolkit::util::Rect4;
+
+
+
+
+
+/// Tests to see if a dumped chunk lists each group's block and location, and reads back the same.
+#[test] fn dump_round_trips() {
+	let mut chunk = Chunk::filled_with(Block::MidnightStone as u8);
+	chunk.fill(Block::Air as u8, Rect4::new((0, 40, 0, 0), (7, 127, 7, 7)));
+	chunk.set_block((1, 40, 2, 3), Block::JackOLantern as u8);
+	chunk.set_block((1, 41, 2, 3), 200);
+	let compressed = chunk.compress();
+	
+	let dump = compressed.dump();
+	assert!(dump.lines().any(|line| line.split_whitespace().collect::<Vec<_>>() == ["10771", "1", "40", "2", "3", "1", "Jack", "o'Lantern"]));
+	assert!(dump.lines().any(|line| line.ends_with("  #200")));
+	assert_eq!(CompressedChunk::from_dump(&dump).unwrap(), compressed);
+	assert_eq!(CompressedChunk::from_dump(&dump).unwrap().decompressed(), chunk);
+}
+
+/// Tests to see if hand-edited dumps are read by their spans alone, and if mistakes are reported by line.
+#[test] fn dump_edits() {
+	let chunk = CompressedChunk::from_dump("# a comment\n\n0 0 0 0 0 600 midnight grass\n  9 9 9 9 9 4 #7\n").unwrap();
+	assert_eq!(chunk.decompressed().get_block((0, 9, 2, 7)), Some(Block::MidnightGrass as u8));
+	assert_eq!(chunk.decompressed().get_block((0, 9, 3, 0)), Some(Block::IronOre as u8));
+	assert_eq!(chunk.decompressed().get_block((0, 9, 3, 4)), Some(Block::Air as u8));
+	
+	assert_eq!(CompressedChunk::from_dump("0 0 0 0 0 5 Stone\n0 0 0 0 5 Dirt"), Err(ChunkDumpError::MalformedLine(2)));
+	assert_eq!(CompressedChunk::from_dump("0 0 0 0 0 0 Stone"), Err(ChunkDumpError::MalformedLine(1)));
+	assert_eq!(CompressedChunk::from_dump("0 0 0 0 0 5 Bedrock"), Err(ChunkDumpError::UnknownBlock {line: 1, name: "Bedrock".to_string()}));
+	assert_eq!(CompressedChunk::from_dump("0 0 0 0 0 65530 Stone\n0 0 0 0 0 10 Dirt"), Err(ChunkDumpError::TooMuchData {line: 2, excess: 4}));
+	assert_eq!(CompressedChunk::from_dump(&format!("0 0 0 0 0 1 Stone\n0 0 0 0 0 {} Dirt", usize::MAX)), Err(ChunkDumpError::TooMuchData {line: 2, excess: usize::MAX-65535}));
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+use fdm_toolkit::world::{Block, World};
+use fdm_toolkit::util::{WorldRect4, Rect4};
+use fdm_toolkit::flood::Adjacency;
+use fdm_toolkit::chunk::Chunk;
+
+
+
+
+
+/// Tests to see if blocks which only touch along an edge are only connected with the right adjacency.
+#[test] fn chunk_components() {
+	assert_eq!(Adjacency::Faces.offsets().count(), 8);
+	assert_eq!(Adjacency::Edges.offsets().count(), 32);
+	assert_eq!(Adjacency::Corners.offsets().count(), 80);
+	
+	let mut chunk = Chunk::filled_with(Block::Air as u8);
+	chunk.fill(Block::IronOre as u8, Rect4::new((1, 1, 1, 1), (2, 2, 1, 1)));
+	chunk.set_block((3, 3, 1, 1), Block::IronOre as u8);
+	chunk.set_block((4, 4, 2, 2), Block::IronOre as u8);
+	
+	let is_ore = |id| id == Block::IronOre as u8;
+	assert_eq!(chunk.components(Adjacency::Faces, is_ore).iter().map(Vec::len).collect::<Vec<_>>(), [4, 1, 1]);
+	assert_eq!(chunk.components(Adjacency::Edges, is_ore).iter().map(Vec::len).collect::<Vec<_>>(), [5, 1]);
+	assert_eq!(chunk.components(Adjacency::Corners, is_ore).iter().map(Vec::len).collect::<Vec<_>>(), [6]);
+	assert_eq!(chunk.connected((1, 1, 1, 1), Adjacency::Faces, is_ore).len(), 4);
+	assert!(chunk.connected((0, 0, 0, 0), Adjacency::Faces, is_ore).is_empty());
+	assert_eq!(chunk.components(Adjacency::Faces, |id| id == Block::Air as u8).len(), 1);
+}
+
+
+
+/// Tests to see if flood fills cross chunk borders, and find floating blocks and enclosed regions.
+#[test] fn world_flood_fill() {
+	let mut world = World::new();
+	let area = WorldRect4::new((-8, 0, -8, -8), (7, 31, 7, 7));
+	world.fill(Block::Stone as u8, WorldRect4::new((-8, 0, -8, -8), (7, 9, 7, 7)));
+	world.fill(Block::Air as u8, WorldRect4::new((-8, 10, -8, -8), (7, 31, 7, 7)));
+	world.fill(Block::Dirt as u8, WorldRect4::new((-2, 20, -2, -2), (1, 21, 1, 1)));
+	world.fill(Block::Air as u8, WorldRect4::new((-3, 3, -3, -3), (2, 5, 2, 2)));
+	
+	assert_eq!(world.components(area, Adjacency::Faces, |id| id == Block::Air as u8).iter().map(Vec::len).collect::<Vec<_>>(), [16*22*16*16-4*2*4*4, 6*3*6*6]);
+	assert_eq!(world.floating_blocks(area, Adjacency::Faces).iter().map(Vec::len).collect::<Vec<_>>(), [4*2*4*4]);
+	assert!(world.floating_blocks(WorldRect4::new((-2, 0, -8, -8), (7, 31, 7, 7)), Adjacency::Faces).is_empty());
+	
+	world.set_history_budget(1 << 20);
+	assert_eq!(world.bucket_fill((0, 4, 0, 0), Block::Lava as u8, area, Adjacency::Faces), 6*3*6*6);
+	assert_eq!(world.get_block((-3, 3, -3, -3)), Some(Block::Lava as u8));
+	assert_eq!(world.get_block((-3, 6, -3, -3)), Some(Block::Stone as u8));
+	assert_eq!(world.bucket_fill((0, 4, 0, 0), Block::Lava as u8, area, Adjacency::Faces), 0);
+	assert!(world.undo());
+	assert_eq!(world.get_block((-3, 3, -3, -3)), Some(Block::Air as u8));
+}
\ No newline at end of file
@@ -0,0 +1,78 @@
+use fdm_toolkit::util::{WorldRect4, FillParams, Rect4};
+use fdm_toolkit::world::{Block, World};
+use fdm_toolkit::chunk::Chunk;
+
+
+
+
+
+/// Tests to see if fills, pastes and replacements can be undone and redone.
+#[test] fn undo_and_redo() {
+	let mut world = World::new();
+	world.insert_chunk((0, 0, 0), Chunk::filled_with(Block::Stone as u8));
+	world.set_history_budget(1 << 20);
+	let original = world.clone();
+	
+	world.fill(Block::Dirt as u8, WorldRect4::new((4, 10, 4, 4), (11, 20, 5, 5)));
+	let filled = world.clone();
+	assert_eq!(world.chunks().count(), 2);
+	
+	let schematic = world.extract(WorldRect4::new((4, 10, 4, 4), (11, 20, 5, 5)));
+	world.paste(&schematic, (0, 50, 0, 0));
+	world.replace(Block::Dirt as u8, Block::Sand as u8);
+	assert_eq!(world.history().undo_len(), 3);
+	
+	assert!(world.undo() && world.undo());
+	assert_eq!(world, filled);
+	assert!(world.undo());
+	assert_eq!(world, original);
+	assert!(!world.undo());
+	assert_eq!(world.chunks().count(), 1);
+	
+	assert!(world.redo());
+	assert_eq!(world, filled);
+	assert_eq!(world.history().redo_len(), 2);
+	
+	world.set_block((0, 0, 0, 0), Block::Glass as u8);
+	assert_eq!(world.history().redo_len(), 0);
+	assert!(!world.redo());
+	assert!(world.undo());
+	assert_eq!(world, filled);
+}
+
+
+
+/// Tests to see if the oldest edits are forgotten once the history's budget is exceeded.
+#[test] fn history_budget() {
+	let mut world = World::new();
+	world.set_history_budget(4096);
+	
+	for i in 0..64 {
+		world.fill_with_params((0, 0, 0, 0), FillParams::random(i, &[(Block::Stone as u8, 1), (Block::Dirt as u8, 1)], Rect4::new((0, 0, 0, 0), (3, 3, 3, 3))));
+		assert!(world.history().used() <= world.history().budget());
+	}
+	assert!(world.history().undo_len() < 64);
+	
+	let mut undone = 0;
+	while world.undo() { undone += 1; }
+	assert_eq!(undone, world.history().redo_len());
+	assert_ne!(world.get_block((0, 0, 0, 0)), Some(Block::Air as u8));
+	
+	world.set_history_budget(0);
+	world.set_block((0, 0, 0, 0), Block::Air as u8);
+	assert!(!world.undo());
+	
+	let noise = FillParams::random(7, &[(Block::Stone as u8, 1), (Block::Dirt as u8, 1)], Rect4::new((0, 0, 0, 0), (7, 127, 7, 7)));
+	world.fill_with_params((0, 0, 0, 0), noise);
+	world.set_history_budget(4096);
+	world.batch(|world| {
+		world.fill(Block::Sand as u8, WorldRect4::new((0, 0, 0, 0), (7, 127, 7, 7)));
+		assert!(world.history().used() <= world.history().budget());
+	});
+	assert_eq!((world.history().undo_len(), world.history().used()), (0, 0));
+	
+	world.set_block((100, 0, 0, 0), Block::Air as u8);
+	assert_eq!(world.chunks().count(), 2);
+	assert!(world.undo());
+	assert_eq!(world.chunks().count(), 1);
+}
\ No newline at end of file
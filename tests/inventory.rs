@@ -0,0 +1,66 @@
+use fdm_toolkit::inventory::{ItemStack, Inventory};
+use fdm_toolkit::collectable::Item;
+use fdm_toolkit::world::Block;
+
+
+
+
+
+/// Tests to see if stacks are added, removed, merged and sorted within their limits.
+#[test] fn inventory_operations() {
+	let mut inventory = Inventory::player();
+	assert_eq!(inventory.add(ItemStack::new(Block::Stone, 100)), None);
+	assert_eq!(inventory.add(ItemStack::new(Item::IronPick, 2)), None);
+	assert_eq!(inventory.add(ItemStack::new(Block::Stone, 20)), None);
+	assert_eq!(inventory.iter().map(|(slot, stack)| (slot, stack.count)).collect::<Vec<_>>(), [(0, 64), (1, 56), (2, 1), (3, 1)]);
+	assert_eq!(inventory.count(Block::Stone), 120);
+	
+	assert_eq!(inventory.remove(Block::Stone, 60), 60);
+	assert_eq!(inventory.get(1), None);
+	assert_eq!(inventory.get(0).map(|stack| stack.count), Some(60));
+	assert_eq!(inventory.remove(Item::IronPick, 5), 2);
+	
+	inventory.set(10, Some(ItemStack::new(Block::Dirt, 10)));
+	inventory.set(20, Some(ItemStack::new(Block::Stone, 10)));
+	inventory.set(30, Some(ItemStack::new(Item::Rock, 3)));
+	inventory.set(31, Some(ItemStack::new(Block::Dirt, 5)));
+	inventory.sort(Inventory::HOTBAR);
+	assert_eq!(inventory.get(0), Some(&ItemStack::new(Block::Stone, 60)));
+	assert_eq!(inventory.iter().skip(1).map(|(slot, stack)| (slot, *stack)).collect::<Vec<_>>(), [
+		(8, ItemStack::new(Block::Dirt, 15).at(8)),
+		(9, ItemStack::new(Block::Stone, 10).at(9)),
+		(10, ItemStack::new(Item::Rock, 3).at(10))
+	]);
+	
+	inventory.merge();
+	assert_eq!(inventory.get(0), Some(&ItemStack::new(Block::Stone, 64)));
+	assert_eq!(inventory.get(9), Some(&ItemStack::new(Block::Stone, 6).at(9)));
+	assert_eq!(inventory.place(ItemStack::new(Item::Compass, 1).at(31)), None);
+	assert_eq!(inventory.set(31, None).map(|stack| stack.slot()), Some(31));
+	assert_eq!(inventory.place(ItemStack::new(Item::Compass, 1).at(Inventory::PLAYER_SLOTS)), None);
+	assert_eq!(inventory.get(Inventory::PLAYER_SLOTS), None);
+	
+	let mut full = Inventory::new(2);
+	assert_eq!(full.add(ItemStack::new(Item::Hammer, 3)), Some(ItemStack::new(Item::Hammer, 1)));
+	assert_eq!(full.get(1).map(ItemStack::slot), Some(1));
+	full.clear();
+	assert!(full.is_empty());
+}
+
+
+
+/// Tests to see if inventories survive a round-trip through serde.
+#[cfg(feature="serde")]
+#[test] fn inventory_serde() {
+	let mut inventory = Inventory::player();
+	inventory.set(0, Some(ItemStack::new(Block::MidnightGrass, 12)));
+	inventory.set(7, Some(ItemStack::new(Item::KleinBottle, 1)));
+	
+	let json = serde_json::to_string(&inventory).unwrap();
+	assert!(json.contains(r#"{"slot":7,"collectable":{"item":"klein_bottle"},"count":1}"#));
+	assert_eq!(serde_json::from_str::<Inventory>(&json).unwrap(), inventory);
+	
+	// Stacks are put in the slot they are found in, whichever slot they claim.
+	let moved = json.replace(r#""slot":7"#, r#""slot":3"#);
+	assert_eq!(serde_json::from_str::<Inventory>(&moved).unwrap(), inventory);
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use fdm_toolkit::util::{WorldRect4, FillParams, Rect4};
+use fdm_toolkit::transform::Axis;
+use fdm_toolkit::world::World;
+
+
+
+
+
+/// Tests to see if seeded patterns are seamless between separate fills, and follow their weights.
+#[test] fn random_is_seamless() {
+	const MIX:[(u8, u32); 3] = [(3, 7), (7, 2), (2, 1)];
+	
+	let mut whole = World::new();
+	whole.fill_with_params((-8, 0, 0, 0), FillParams::random(42, &MIX, Rect4::new((0, 0, 0, 0), (15, 15, 7, 7))));
+	
+	let mut halves = World::new();
+	halves.fill_with_params((-8, 0, 0, 0), FillParams::random(42, &MIX, Rect4::new((0, 0, 0, 0), (7, 15, 7, 7))));
+	halves.fill_with_params(( 0, 0, 0, 0), FillParams::random(42, &MIX, Rect4::new((0, 0, 0, 0), (7, 15, 7, 7))));
+	assert_eq!(whole, halves);
+	
+	let stone = WorldRect4::new((-8, 0, 0, 0), (7, 15, 7, 7)).iter().filter(|&p| whole.get_block(p) == Some(3)).count();
+	assert!((stone as f64 / 16384.0 - 0.7).abs() < 0.05);
+	
+	let mut other = World::new();
+	other.fill_with_params((-8, 0, 0, 0), FillParams::random(43, &MIX, Rect4::new((0, 0, 0, 0), (15, 15, 7, 7))));
+	assert_ne!(whole, other);
+}
+
+
+
+/// Tests to see if gradients, checkerboards and noise masks are laid out as documented.
+#[test] fn laid_out_patterns() {
+	let mut world = World::new();
+	world.fill_with_params((0, 0, 0, 0), FillParams::gradient(1, &[1, 2, 3], Axis::Y, Rect4::new((0, 0, 0, 0), (3, 20, 3, 3))));
+	assert_eq!(world.get_block((1, 0, 1, 1)), Some(1));
+	assert_eq!(world.get_block((1, 10, 1, 1)), Some(2));
+	assert_eq!(world.get_block((1, 20, 1, 1)), Some(3));
+	
+	world.fill_with_params((-2, 0, 0, 0), FillParams::checkerboard((4, 5), 2, Rect4::new((0, 0, 0, 0), (3, 3, 3, 3))));
+	assert_eq!(world.get_block((-2, 0, 0, 0)), Some(5));
+	assert_eq!(world.get_block(( 0, 0, 0, 0)), Some(4));
+	assert_eq!(world.get_block(( 1, 2, 0, 0)), Some(5));
+	
+	let mut world = World::new();
+	world.fill(9, WorldRect4::new((0, 0, 0, 0), (15, 15, 15, 15)));
+	world.fill_with_params((0, 0, 0, 0), FillParams::solid(1, Rect4::new((0, 0, 0, 0), (15, 15, 15, 15))).masked_by_noise(5, 4.0, 0.5));
+	let masked = WorldRect4::new((0, 0, 0, 0), (15, 15, 15, 15)).iter().filter(|&p| world.get_block(p) == Some(9)).count();
+	assert!(masked > 0 && masked < 65536);
+}
\ No newline at end of file
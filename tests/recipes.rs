@@ -0,0 +1,69 @@
+use fdm_toolkit::recipes::{RecipeBook, Recipe};
+use fdm_toolkit::collectable::{AnyCollectable, Item};
+use fdm_toolkit::inventory::{ItemStack, Inventory};
+use fdm_toolkit::world::Block;
+
+
+
+
+
+/// Tests to see if recipes are expanded into raw materials, and matched against inventories.
+#[test] fn recipe_queries() {
+	use AnyCollectable::{Block as B, Item as I};
+	
+	let mut book = [
+		Recipe::new(Item::Stick,        4, [(B(Block::Wood), 1)]),
+		Recipe::new(Item::Stick,        4, [(B(Block::MidnightWood), 1)]),
+		Recipe::new(Item::Hammer,       1, [(I(Item::Stick), 2), (I(Item::Rock), 3)]),
+		Recipe::new(Item::IronBars,     1, [(B(Block::IronOre), 1)]),
+		Recipe::new(Item::DeadlyBars,   1, [(B(Block::DeadlyOre), 1), (I(Item::IronBars), 1)]),
+		Recipe::new(Item::SolenoidBars, 1, [(B(Block::SolenoidOre), 1), (I(Item::DeadlyBars), 1)]),
+		Recipe::new(Item::Ultrahammer,  1, [(I(Item::Hammer), 1), (I(Item::DeadlyBars), 3), (I(Item::SolenoidBars), 3)])
+	].into_iter().collect::<RecipeBook>();
+	book.extend([
+		Recipe::new(Block::Glass,  1, [(B(Block::Sand), 1), (B(Block::Wood), 1)]),
+		Recipe::new(Item::Compass, 1, [(I(Item::IronBars), 2), (I(Item::DeadlyBars), 1)])
+	]);
+	assert_eq!(book.recipes().len(), 9);
+	
+	assert_eq!(book.raw_materials(Item::Ultrahammer, 1), [
+		(AnyCollectable::Block(Block::Wood), 1),
+		(AnyCollectable::Item(Item::Rock), 3),
+		(AnyCollectable::Block(Block::DeadlyOre), 6),
+		(AnyCollectable::Block(Block::IronOre), 6),
+		(AnyCollectable::Block(Block::SolenoidOre), 3)
+	]);
+	assert_eq!(book.raw_materials(Block::Sand, 5), [(AnyCollectable::Block(Block::Sand), 5)]);
+	assert_eq!(book.raw_materials(Item::Stick, u32::MAX), [(AnyCollectable::Block(Block::Wood), u32::MAX.div_ceil(4))]);
+	assert!(book.raw_materials(Item::Ultrahammer, u32::MAX).contains(&(AnyCollectable::Block(Block::DeadlyOre), u32::MAX)));
+	assert_eq!(book.recipes_for(Item::Stick).count(), 2);
+	assert!(book.uses_of(Item::DeadlyBars).any(|recipe| recipe.output.collectable == AnyCollectable::Item(Item::Compass)));
+	
+	let mut inventory = Inventory::player();
+	inventory.add(ItemStack::new(Block::Wood, 3));
+	inventory.add(ItemStack::new(Block::Sand, 2));
+	let mut craftable = book.craftable(&inventory).map(|(recipe, n)| (recipe.output.collectable, n)).collect::<Vec<_>>();
+	craftable.sort_by_key(|&(collectable, n)| (collectable.index(), n));
+	assert_eq!(craftable, [(AnyCollectable::Block(Block::Glass), 2), (AnyCollectable::Item(Item::Stick), 3)]);
+}
+
+
+
+/// Tests to see if recipe books can be exported, extended and imported again, and cycles are broken.
+#[test] fn recipe_book_serde() {
+	let mut book = RecipeBook::new();
+	book.add(Recipe::new(Block::Dirt, 1, [(AnyCollectable::Block(Block::Grass), 2)]));
+	book.add(Recipe::new(Block::Grass, 1, [(AnyCollectable::Block(Block::Dirt), 1)]));
+	
+	assert_eq!(book.raw_materials(Block::Dirt, 1), [(AnyCollectable::Block(Block::Dirt), 2)]);
+	
+	#[cfg(feature="serde")] {
+		let json = serde_json::to_string(&book).unwrap();
+		assert!(json.starts_with(r#"[{"inputs":[{"slot":0,"collectable":{"block":"grass"},"count":2}],"output":{"slot":0,"collectable":{"block":"dirt"},"count":1}}"#));
+		assert_eq!(serde_json::from_str::<RecipeBook>(&json).unwrap(), book);
+		
+		// Stacks need not name a slot, so recipes can be imported from a plain list.
+		let imported = serde_json::from_str::<RecipeBook>(r#"[{"inputs":[{"collectable":{"block":"wood"},"count":1}],"output":{"collectable":{"item":"stick"},"count":4}}]"#).unwrap();
+		assert_eq!(imported, [Recipe::new(Item::Stick, 4, [(AnyCollectable::Block(Block::Wood), 1)])].into_iter().collect());
+	}
+}
\ No newline at end of file
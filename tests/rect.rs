@@ -0,0 +1,52 @@
+use fdm_toolkit::util::{WorldRect4, Rect4};
+
+
+
+
+
+/// Tests to see if the set operations on [`Rect4`] agree with each other.
+#[test] fn rect_algebra() {
+	let a = Rect4::new((4, 4, 4, 4), (0, 0, 0, 0));
+	let b = Rect4::from_corners((2, 3, 1, 0), (6, 5, 2, 9));
+	
+	assert_eq!(a.normalized(), Rect4::new((0, 0, 0, 0), (4, 4, 4, 4)));
+	assert_eq!(a.volume(), 625);
+	assert_eq!(a.intersection(&b), Some(Rect4::new((2, 3, 1, 0), (4, 4, 2, 4))));
+	assert_eq!(a.union(&b), Rect4::new((0, 0, 0, 0), (6, 5, 4, 9)));
+	assert_eq!(a.intersection(&Rect4::new((5, 0, 0, 0), (6, 0, 0, 0))), None);
+	
+	assert!(a.contains((4, 0, 2, 3)) && !a.contains((5, 0, 2, 3)));
+	assert!(a.union(&b).contains_rect(&b));
+	assert_eq!(a.shrink(2), Some(Rect4::new((2, 2, 2, 2), (2, 2, 2, 2))));
+	assert_eq!(a.shrink(3), None);
+	assert_eq!(a.expand(1), Rect4::new((0, 0, 0, 0), (5, 5, 5, 5)));
+	assert_eq!(a.translate((-1, 0, 0, 0)), None);
+	
+	assert_eq!(b.iter().count(), b.volume());
+	assert!(b.iter().all(|p| b.contains(p)));
+	assert_eq!(b.iter().next(), Some((2, 3, 1, 0)));
+	assert_eq!(b.iter().last(), Some((6, 5, 2, 9)));
+	
+	// Sizes and volumes of areas too large to count saturate, rather than overflowing.
+	let huge = WorldRect4::new((i64::MIN, 0, 0, 0), (i64::MAX, 0, 0, 0));
+	assert_eq!(huge.size(), (usize::MAX, 1, 1, 1));
+	assert_eq!(WorldRect4::new((i64::MIN, i64::MIN, 0, 0), (i64::MAX, i64::MAX, 0, 0)).volume(), usize::MAX);
+	assert_eq!(huge.expand(1), WorldRect4::new((i64::MIN, -1, -1, -1), (i64::MAX, 1, 1, 1)));
+	assert_eq!(huge.shrink(i64::MAX), None);
+	assert_eq!(WorldRect4::with_size((i64::MAX, 0, 0, 0), (2, 1, 1, 1)), WorldRect4::new((i64::MAX, 0, 0, 0), (i64::MAX, 0, 0, 0)));
+	assert_eq!(Rect4::new((0, 0, 0, 0), (usize::MAX, 0, 0, 0)).size(), (usize::MAX, 1, 1, 1));
+}
+
+
+
+/// Tests to see if a world-space area is split between chunks correctly.
+#[test] fn split_chunks() {
+	let rect  = WorldRect4::new((-9, -5, 0, 3), (7, 200, 8, 3));
+	let parts = rect.split_chunks().collect::<Vec<_>>();
+	
+	assert_eq!(parts.len(), 3*2);
+	assert_eq!(parts[0], ((-2, 0, 0), Rect4::new((7, 0, 0, 3), (7, 127, 7, 3))));
+	assert_eq!(parts.iter().map(|(_, r)| r.volume()).sum::<usize>(), 17*128*9);
+	assert_eq!(rect.shrink(1), None);
+	assert_eq!(rect.translate((1, 1, 1, 1)).expand(1).size(), (19, 208, 11, 3));
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use fdm_toolkit::collectable::{AnyCollectable, CollectableIndex, CollectableType, Item};
+use fdm_toolkit::registry::Registry;
+use fdm_toolkit::world::Block;
+
+
+
+
+
+/// Tests to see if the registry covers every collectable, with unique indices.
+#[test] fn registry_enumerates_collectables() {
+	let registry = Registry::new();
+	assert_eq!(registry.len(), Block::ChunkBorder as usize + Item::Alidade as usize + 1);
+	
+	for entry in registry.iter() {
+		assert_eq!(entry.index.collectable(), Some(entry.collectable));
+		assert_eq!(registry.get(entry.index), Some(entry));
+	}
+	assert_eq!(registry.iter().position(|entry| entry.collectable == AnyCollectable::Block(Block::Stone)), Some(Block::Stone as usize - 1));
+	
+	let stone = registry.get(Block::Stone).unwrap();
+	assert_eq!((stone.index.get(), stone.name, stone.typ), (Block::Stone as u16, "Stone", CollectableType::Block));
+	assert_eq!(registry.by_name("stone"), Some(stone));
+	assert_eq!(registry.get(Item::Alidade).unwrap().collectable, AnyCollectable::Item(Item::Alidade));
+	assert_eq!(registry.get(CollectableIndex::new(255)), None);
+	assert_eq!(registry.get(Block::Air), None);
+}
+
+/// Tests to see if collectable indices serialize as plain integers.
+#[cfg(feature="serde")]
+#[test] fn collectable_index_serde() {
+	let index = CollectableIndex::from(Item::Alidade);
+	let json = serde_json::to_string(&index).unwrap();
+	assert_eq!(json, index.get().to_string());
+	assert_eq!(serde_json::from_str::<CollectableIndex>(&json).unwrap(), index);
+	assert_eq!(AnyCollectable::try_from(index), Ok(AnyCollectable::Item(Item::Alidade)));
+	assert_eq!(AnyCollectable::try_from(CollectableIndex::new(1000)), Err(CollectableIndex::new(1000)));
+}
\ No newline at end of file
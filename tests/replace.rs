@@ -0,0 +1,47 @@
+use fdm_toolkit::chunk::{CompressedChunk, ChunkData, Chunk};
+use fdm_toolkit::util::{WorldRect4, FillParams, Rect4};
+use fdm_toolkit::world::{Block, World};
+
+
+
+
+
+/// Tests to see if replacing blocks in compressed and decompressed chunks gives the same result.
+#[test] fn replace_in_chunks() {
+	let mut chunk = Chunk::filled_with(Block::Stone as u8);
+	chunk.fill(Block::Grass as u8, Rect4::new((0, 10, 0, 0), (7, 10, 7, 7)));
+	chunk.fill(Block::Air as u8, Rect4::new((0, 11, 0, 0), (7, 127, 7, 7)));
+	
+	let mut compressed = CompressedChunk::new(chunk.compress().to_vec());
+	assert_eq!(compressed.replace(Block::Grass as u8, Block::MidnightGrass as u8), 512);
+	assert_eq!(chunk.replace(Block::Grass as u8, Block::MidnightGrass as u8), 512);
+	assert_eq!(chunk.replace(Block::Grass as u8, Block::MidnightGrass as u8), 0);
+	assert_eq!(compressed.decompressed(), chunk);
+	
+	let only_air = |chunk:&mut Chunk| chunk.fill_where(FillParams::solid(Block::Sand as u8, Rect4::new((0, 9, 0, 0), (7, 12, 7, 7))), |b| b == Block::Air);
+	assert_eq!(only_air(&mut chunk), 2*512);
+	assert_eq!(compressed.fill_where(FillParams::solid(Block::Sand as u8, Rect4::new((0, 9, 0, 0), (7, 12, 7, 7))), |b| b == Block::Air), 2*512);
+	assert_eq!(compressed.decompressed(), chunk);
+	assert_eq!(chunk.get_block((0, 9, 0, 0)), Some(Block::Stone as u8));
+	
+	// Areas reaching past the chunk are clipped to it.
+	assert_eq!(chunk.fill_where(FillParams::solid(Block::Ice as u8, Rect4::new((7, 126, 7, 7), (8, 200, 9, 9))), |_| true), 2);
+	assert_eq!(chunk.fill_where(FillParams::solid(Block::Ice as u8, Rect4::new((8, 0, 0, 0), (9, 0, 0, 0))), |_| true), 0);
+}
+
+
+
+/// Tests to see if world-wide replacements only change (and dirty) what they should.
+#[test] fn replace_in_world() {
+	let mut world = World::new();
+	world.fill(Block::Stone as u8, WorldRect4::new((-8, 0, 0, 0), (7, 15, 7, 7)));
+	world.clear_dirty();
+	
+	let mix = [(Block::IronOre as u8, 1), (Block::Dirt as u8, 1)];
+	let n   = world.fill_where((-8, 0, 0, 0), FillParams::random(1, &mix, Rect4::new((0, 0, 0, 0), (7, 15, 7, 7))), |b| b == Block::Stone);
+	assert_eq!(n, 8*16*8*8);
+	assert_eq!(world.dirty_chunks().collect::<Vec<_>>(), [&(-1, 0, 0)]);
+	
+	assert_eq!(world.replace(Block::Dirt as u8, Block::Stone as u8) + world.replace(Block::IronOre as u8, Block::Stone as u8), n);
+	assert_eq!(world.fill_where((100, 0, 0, 0), FillParams::solid(1, Rect4::new((0, 0, 0, 0), (3, 3, 3, 3))), |_| true), 0);
+}
\ No newline at end of file
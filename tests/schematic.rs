@@ -0,0 +1,50 @@
+use fdm_toolkit::err::SchematicReadError;
+use fdm_toolkit::schematic::Schematic;
+use fdm_toolkit::util::WorldRect4;
+use fdm_toolkit::world::{Block, World};
+
+
+
+
+
+/// Tests to see if a region spanning several chunks can be copied from one world into another.
+#[test] fn extract_and_paste() {
+	let mut world = World::new();
+	world.set_block((-1, 10, 7, 0), Block::Stone as u8);
+	world.set_block(( 0, 11, 8, 1), Block::Chest as u8);
+	world.set_block(( 1, 12, 9, 2), Block::IronOre as u8);
+	
+	let schematic = world.extract(WorldRect4::new((1, 12, 9, 2), (-1, 10, 7, 0)));
+	assert_eq!(schematic.size(), (3, 3, 3, 3));
+	assert_eq!(schematic.get_block((1, 1, 1, 1)), Some(Block::Chest as u8));
+	
+	let mut copy = World::new();
+	copy.paste(&schematic, (100, 0, -100, 20));
+	assert_eq!(copy.get_block((100, 0, -100, 20)), Some(Block::Stone as u8));
+	assert_eq!(copy.get_block((101, 1, -99, 21)), Some(Block::Chest as u8));
+	assert_eq!(copy.get_block((102, 2, -98, 22)), Some(Block::IronOre as u8));
+	assert_eq!(copy.get_block((102, 2, -98, 21)), Some(Block::Air as u8));
+}
+
+
+
+/// Tests to see if a schematic survives being written to, and read from, bytes and JSON.
+#[test] fn schematic_round_trip() {
+	let schematic = Schematic::new((2, 300, 1, 2), (0..600).map(|n| (n / 7) as u8));
+	
+	assert_eq!(Schematic::from_bytes(&schematic.to_bytes()), Ok(schematic.clone()));
+	assert!(Schematic::from_bytes(&schematic.to_bytes()[..30]).is_err());
+	
+	let mut trailing = schematic.to_bytes();
+	trailing.push(0);
+	assert_eq!(Schematic::from_bytes(&trailing), Err(SchematicReadError::TrailingData(trailing.len()-1)));
+	
+	let mut huge = Schematic::new((1, 1, 1, 1), [0]).to_bytes();
+	for at in [5, 9, 13, 17] { huge[at..at+4].copy_from_slice(&u32::MAX.to_le_bytes()); }
+	assert_eq!(Schematic::from_bytes(&huge), Err(SchematicReadError::TooLarge((u32::MAX as usize, u32::MAX as usize, u32::MAX as usize, u32::MAX as usize))));
+	
+	#[cfg(feature="serde")] {
+		let json = serde_json::to_string(&schematic).unwrap();
+		assert_eq!(serde_json::from_str::<Schematic>(&json).unwrap(), schematic);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use fdm_toolkit::chunk::{CompressedChunk, ChunkData, Chunk};
+use fdm_toolkit::search::{distance_squared, veins};
+use fdm_toolkit::world::{Block, World};
+use fdm_toolkit::util::{WorldRect4, Rect4};
+
+
+
+
+
+/// Tests to see if blocks are found the same way in compressed and decompressed chunks.
+#[test] fn find_in_chunks() {
+	let mut chunk = Chunk::filled_with(Block::Stone as u8);
+	chunk.fill(Block::DeadlyOre as u8, Rect4::new((1, 2, 3, 4), (2, 3, 3, 5)));
+	chunk.set_block((7, 127, 7, 7), Block::DeadlyOre as u8);
+	
+	let compressed = chunk.compress();
+	let found      = chunk.find(Block::DeadlyOre as u8).collect::<Vec<_>>();
+	assert_eq!(found.len(), 9);
+	assert_eq!(found.first(), Some(&(1, 2, 3, 4)));
+	assert_eq!(found.last(), Some(&(7, 127, 7, 7)));
+	assert_eq!(compressed.find(Block::DeadlyOre as u8).collect::<Vec<_>>(), found);
+	assert_eq!(compressed.find(Block::Chest as u8).count(), 0);
+	
+	for loc in [(0, 0, 0, 0), (1, 2, 3, 5), (7, 127, 7, 7), (7, 127, 7, 6)] {
+		assert_eq!(compressed.get_block(loc), chunk.get_block(loc));
+	}
+	assert_eq!(CompressedChunk::default().get_block((0, 128, 0, 0)), None);
+}
+
+
+
+/// Tests to see if blocks are found within a region or radius of a point, nearest first, and clustered into veins.
+#[test] fn find_in_world() {
+	let mut world = World::new();
+	world.fill(Block::Stone as u8, WorldRect4::new((-16, 0, -8, -8), (15, 31, 7, 7)));
+	world.fill(Block::SolenoidOre as u8, WorldRect4::new((-10, 5, 0, 0), (-9, 5, 0, 0)));
+	world.fill(Block::SolenoidOre as u8, WorldRect4::new((6, 5, 2, -1), (8, 5, 2, 0)));
+	world.set_block((1, 6, 0, 0), Block::SolenoidOre as u8);
+	
+	let origin = (0, 5, 0, 0);
+	let found  = world.find_blocks(Block::SolenoidOre as u8, WorldRect4::new((-16, 0, -8, -8), (15, 31, 7, 7)), origin);
+	assert_eq!(found.len(), 9);
+	assert_eq!(found[0], (1, 6, 0, 0));
+	assert!(found.windows(2).all(|pair| distance_squared(pair[0], origin) <= distance_squared(pair[1], origin)));
+	
+	let veins = veins(&found);
+	assert_eq!(veins.iter().map(Vec::len).collect::<Vec<_>>(), [1, 6, 2]);
+	
+	assert_eq!(world.find_blocks_near(Block::SolenoidOre as u8, origin, 7.0).len(), 3);
+	assert_eq!(world.find_blocks(Block::SolenoidOre as u8, WorldRect4::new((0, 0, 0, 0), (15, 31, 7, 7)), origin).len(), 4);
+	
+	// Only loaded chunks are searched, so even the largest regions (and radii) are quick to search.
+	let everywhere = WorldRect4::new((i64::MIN, i64::MIN, i64::MIN, i64::MIN), (i64::MAX, i64::MAX, i64::MAX, i64::MAX));
+	assert_eq!(world.find_blocks(Block::SolenoidOre as u8, everywhere, origin), found);
+	assert_eq!(world.find_blocks_near(Block::SolenoidOre as u8, origin, f64::INFINITY).len(), 9);
+	assert_eq!(world.find_blocks_near(Block::SolenoidOre as u8, (i64::MAX, 5, 0, 0), 7.0), []);
+	assert!(world.find_blocks(Block::Chest as u8, WorldRect4::new((-16, 0, -8, -8), (15, 31, 7, 7)), origin).is_empty());
+}
\ No newline at end of file
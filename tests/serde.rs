@@ -0,0 +1,96 @@
+#![cfg(feature="serde")]
+
+use fdm_toolkit::chunk::{CompressedChunk, BlockGroup, ChunkData, Chunk};
+use fdm_toolkit::inventory::{ItemStack, Inventory};
+use fdm_toolkit::util::{WorldRect4, Rect4};
+use fdm_toolkit::world::{Block, World};
+use fdm_toolkit::collectable::CollectableType;
+
+use serde_derive::{Deserialize, Serialize};
+
+
+
+
+
+#[derive(Deserialize, PartialEq, Serialize, Debug)]
+struct Typed {
+	typ:CollectableType
+}
+
+
+
+/// Tests to see if collectable types round trip through JSON, TOML and bincode.
+#[test] fn collectable_type_round_trips() {
+	for typ in CollectableType::ALL {
+		let json = serde_json::to_string(&typ).unwrap();
+		assert_eq!(json, format!("\"{typ}\""));
+		assert_eq!(serde_json::from_str::<CollectableType>(&json).unwrap(), typ);
+		
+		let toml = toml::to_string(&Typed {typ}).unwrap();
+		assert_eq!(toml::from_str::<Typed>(&toml).unwrap(), Typed {typ});
+		
+		let bytes = bincode::serialize(&typ).unwrap();
+		assert_eq!(bytes, [typ as u8]);
+		assert_eq!(bincode::deserialize::<CollectableType>(&bytes).unwrap(), typ);
+	}
+}
+
+/// Tests to see if collectable types can be read from aliases, indices and enum-like maps.
+#[test] fn collectable_type_aliases() {
+	assert_eq!(serde_json::from_str::<CollectableType>("\"material\"").unwrap(), CollectableType::Item);
+	assert_eq!(serde_json::from_str::<CollectableType>("\"Tool\"").unwrap(), CollectableType::Tool);
+	assert_eq!(serde_json::from_str::<CollectableType>("2").unwrap(), CollectableType::Tool);
+	assert_eq!(serde_json::from_str::<CollectableType>("{\"Block\":null}").unwrap(), CollectableType::Block);
+	assert_eq!(toml::from_str::<Typed>("typ = 1").unwrap(), Typed {typ:CollectableType::Item});
+	assert_eq!(toml::from_str::<Typed>("typ = \"MATERIAL\"").unwrap(), Typed {typ:CollectableType::Item});
+	
+	assert!(serde_json::from_str::<CollectableType>("\"weapon\"").is_err());
+	assert!(serde_json::from_str::<CollectableType>("3").is_err());
+	assert!(serde_json::from_str::<CollectableType>("-1").is_err());
+	assert!(bincode::deserialize::<CollectableType>(&[3]).is_err());
+}
+
+/// Tests to see if chunks are serialized as readable groups in JSON, and as bytes in bincode.
+#[test] fn chunk_round_trips() {
+	let mut chunk = Chunk::filled_with(Block::Stone as u8);
+	chunk.fill(Block::Air as u8, Rect4::new((0, 64, 0, 0), (7, 127, 7, 7)));
+	chunk.set_block((2, 64, 3, 4), Block::Chest as u8);
+	let compressed = chunk.compress();
+	
+	let json = serde_json::to_value(&compressed).unwrap();
+	assert_eq!(json[0], serde_json::json!({"block": Block::Stone as u8, "span": 255}));
+	assert_eq!(serde_json::from_value::<CompressedChunk>(json).unwrap(), compressed);
+	let partial = serde_json::from_str::<Chunk>("[{\"block\": 3, \"span\": 10}]").unwrap();
+	assert_eq!((partial.get_block((0, 0, 1, 1)), partial.get_block((0, 0, 1, 2))), (Some(Block::Stone as u8), Some(Block::Air as u8)));
+	assert!(serde_json::from_str::<CompressedChunk>(&format!("[{}{{\"block\": 3, \"span\": 2}}]", "{\"block\": 3, \"span\": 255},".repeat(257))).is_err());
+	
+	let bytes = bincode::serialize(&compressed).unwrap();
+	assert_eq!(bytes.len(), 8 + compressed.len()*2);
+	assert_eq!(bincode::deserialize::<Chunk>(&bytes).unwrap(), chunk);
+	assert_eq!(bincode::deserialize::<BlockGroup>(&[1, 2]).unwrap(), BlockGroup {block_id: 1, span: 2});
+	
+	let rect = Rect4::new((1, 2, 3, 4), (5, 6, 7, 0));
+	assert_eq!(serde_json::from_str::<Rect4>(&serde_json::to_string(&rect).unwrap()).unwrap(), rect);
+}
+
+/// Tests to see if worlds are serialized keyed by chunk position, along with their chests.
+#[test] fn world_round_trips() {
+	let mut world = World::new();
+	world.fill(Block::Dirt as u8, WorldRect4::new((-8, 0, -8, 0), (7, 2, 7, 7)));
+	let mut contents = Inventory::new(4);
+	contents.add(ItemStack::new(Block::Sand, 12));
+	world.place_chest((-1, 3, 2, 5), contents);
+	
+	let json = serde_json::to_value(&world).unwrap();
+	assert!(json["chunks"]["-1,-1,0"].is_array());
+	assert_eq!(json["chunks"].as_object().unwrap().len(), 4);
+	assert_eq!(serde_json::from_value::<World>(json).unwrap(), world);
+	assert_eq!(bincode::deserialize::<World>(&bincode::serialize(&world).unwrap()).unwrap(), world);
+	
+	// Chests whose location does not hold a chest are left out.
+	let mut json = serde_json::to_value(&world).unwrap();
+	json["chests"][0][0] = serde_json::json!([-1, 2, 2, 5]);
+	assert!(serde_json::from_value::<World>(json).unwrap().chests().is_empty());
+	
+	assert!(serde_json::from_str::<World>("{\"chunks\": {\"1,2\": []}, \"chests\": []}").is_err());
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+use fdm_toolkit::util::{FillParams, ShapeStyle, Rect4};
+use fdm_toolkit::chunk::{ChunkData, Chunk};
+use fdm_toolkit::transform::{Plane, Axis};
+
+
+
+
+
+/// Tests to see if shapes only touch the blocks inside of them.
+#[test] fn hypersphere() {
+	let rect = Rect4::new((0, 0, 0, 0), (6, 6, 6, 6));
+	
+	let mut chunk = Chunk::filled_with(3);
+	chunk.fill_with_params(FillParams::hypersphere(1, rect, ShapeStyle::Solid));
+	assert_eq!(chunk.get_block((3, 3, 3, 3)), Some(1));
+	assert_eq!(chunk.get_block((0, 3, 3, 3)), Some(1));
+	assert_eq!(chunk.get_block((0, 0, 0, 0)), Some(3));
+	assert_eq!(chunk.get_block((1, 1, 1, 1)), Some(3));
+	
+	let mut chunk = Chunk::filled_with(3);
+	chunk.fill_with_params(FillParams::hypersphere(1, rect, ShapeStyle::Shell(1)));
+	assert_eq!(chunk.get_block((3, 3, 3, 3)), Some(0));
+	assert_eq!(chunk.get_block((0, 3, 3, 3)), Some(1));
+	assert_eq!(chunk.get_block((0, 0, 0, 0)), Some(3));
+	
+	let mut thinnest = Chunk::filled_with(3);
+	thinnest.fill_with_params(FillParams::hypersphere(1, rect, ShapeStyle::Shell(0)));
+	assert_eq!(thinnest, chunk);
+}
+
+
+
+/// Tests to see if the other shapes are oriented as documented.
+#[test] fn oriented_shapes() {
+	let rect = Rect4::new((0, 0, 0, 0), (7, 7, 7, 7));
+	
+	let mut chunk = Chunk::filled_with(0);
+	chunk.fill_with_params(FillParams::simplex(1, rect, ShapeStyle::Solid));
+	assert_eq!(chunk.get_block((0, 0, 0, 0)), Some(1));
+	assert_eq!(chunk.get_block((6, 0, 0, 0)), Some(1));
+	assert_eq!(chunk.get_block((4, 4, 0, 0)), Some(0));
+	
+	let mut chunk = Chunk::filled_with(0);
+	chunk.fill_with_params(FillParams::hypercone(1, rect, Axis::Y, ShapeStyle::Solid));
+	assert_eq!(chunk.get_block((0, 0, 3, 3)), Some(1));
+	assert_eq!(chunk.get_block((0, 7, 3, 3)), Some(0));
+	
+	let mut chunk = Chunk::filled_with(0);
+	chunk.fill_with_params(FillParams::spherinder(1, rect, Axis::W, ShapeStyle::Solid));
+	assert_eq!(chunk.get_block((3, 3, 3, 0)), Some(1));
+	assert_eq!(chunk.get_block((0, 0, 3, 3)), Some(0));
+	
+	let mut chunk = Chunk::filled_with(0);
+	chunk.fill_with_params(FillParams::cubinder(1, rect, Plane::XY, ShapeStyle::Solid));
+	assert_eq!(chunk.get_block((3, 3, 0, 0)), Some(1));
+	assert_eq!(chunk.get_block((0, 0, 3, 3)), Some(0));
+	
+	let mut chunk = Chunk::filled_with(0);
+	chunk.fill_with_params(FillParams::duocylinder(1, rect, Plane::XZ, ShapeStyle::Solid));
+	assert_eq!(chunk.get_block((0, 3, 3, 4)), Some(1));
+	assert_eq!(chunk.get_block((3, 0, 3, 0)), Some(0));
+}
+
+
+/// Tests to see if shapes can be combined, such as a hollow hypersphere with a doorway cut into it.
+#[test] fn combined_shapes() {
+	let sphere = || FillParams::hypersphere(1, Rect4::new((0, 0, 0, 0), (6, 6, 6, 6)), ShapeStyle::Shell(1));
+	let door   = || FillParams::solid(0, Rect4::new((0, 0, 2, 2), (1, 3, 4, 4)));
+	
+	let mut chunk = Chunk::filled_with(3);
+	chunk.fill_with_params(door().union(sphere()));
+	assert_eq!(chunk.get_block((0, 3, 3, 3)), Some(0));
+	assert_eq!(chunk.get_block((6, 3, 3, 3)), Some(1));
+	assert_eq!(chunk.get_block((0, 0, 0, 0)), Some(3));
+	
+	let mut chunk = Chunk::filled_with(3);
+	chunk.fill_with_params(sphere().difference(door()));
+	assert_eq!(chunk.get_block((0, 3, 3, 3)), Some(3));
+	assert_eq!(chunk.get_block((6, 3, 3, 3)), Some(1));
+	
+	let mut chunk = Chunk::filled_with(3);
+	chunk.fill_with_params(sphere().intersection(door()));
+	assert_eq!(chunk.get_block((0, 3, 3, 3)), Some(1));
+	assert_eq!(chunk.get_block((6, 3, 3, 3)), Some(3));
+	
+	let mut chunk = Chunk::filled_with(3);
+	chunk.fill_with_params(door().xor(sphere()));
+	assert_eq!(chunk.get_block((0, 3, 3, 3)), Some(3));
+	assert_eq!(chunk.get_block((0, 0, 2, 2)), Some(0));
+	assert_eq!(chunk.get_block((6, 3, 3, 3)), Some(1));
+}
\ No newline at end of file
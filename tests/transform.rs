@@ -0,0 +1,55 @@
+use fdm_toolkit::transform::{Transform, Plane, Axis};
+use fdm_toolkit::chunk::{ChunkData, Chunk};
+use fdm_toolkit::util::{WorldRect4, Rect4};
+use fdm_toolkit::schematic::Schematic;
+use fdm_toolkit::world::World;
+
+
+
+
+
+/// Tests to see if quarter-turns, reflections and their inverses move blocks where they should.
+#[test] fn rotate_schematic() {
+	let schematic = Schematic::new((2, 3, 4, 5), (0..120).map(|n| n as u8));
+	
+	let turned = schematic.transformed(Transform::rotation(Plane::XW, 1));
+	assert_eq!(turned.size(), (5, 3, 4, 2));
+	assert_eq!(turned.get_block((4, 2, 3, 1)), schematic.get_block((1, 2, 3, 0)));
+	
+	for plane in [Plane::XY, Plane::XZ, Plane::XW, Plane::YZ, Plane::YW, Plane::ZW] {
+		let t = Transform::rotation(plane, 1).then(Transform::mirror(Axis::Z));
+		assert_eq!(schematic.transformed(t).transformed(t.inverse()), schematic);
+		assert_eq!(Transform::rotation(plane, 4), Transform::IDENTITY);
+		assert_eq!(Transform::rotation(plane, -1), Transform::rotation(plane, 3));
+	}
+}
+
+
+
+/// Tests to see if chunks can only be turned in ways that keep their shape.
+#[test] fn rotate_chunk() {
+	let mut chunk = Chunk::filled_with(0);
+	chunk.set_block((1, 100, 2, 3), 7);
+	
+	let turned = chunk.transformed(Transform::rotation(Plane::ZW, 1)).unwrap();
+	assert_eq!(turned.get_block((1, 100, 4, 2)), Some(7));
+	
+	let flipped = chunk.transformed(Transform::rotation(Plane::XY, 2)).unwrap();
+	assert_eq!(flipped.get_block((6, 27, 2, 3)), Some(7));
+	
+	assert!(chunk.transformed(Transform::rotation(Plane::YW, 1)).is_none());
+	assert_eq!(Transform::rotation(Plane::XY, 1).apply_rect(Rect4::new((3, 1, 0, 0), (1, 2, 0, 0))), Rect4::new((1, 1, 0, 0), (2, 3, 0, 0)));
+}
+
+
+
+/// Tests to see if a transformed paste covers the area it reports.
+#[test] fn paste_transformed() {
+	let schematic = Schematic::new((3, 1, 1, 2), [1, 2, 3, 4, 5, 6]);
+	
+	let mut world = World::new();
+	let bounds = world.paste_transformed(&schematic, (-2, 5, 0, 0), Transform::rotation(Plane::XZ, 1));
+	assert_eq!(bounds, WorldRect4::new((-2, 5, 0, 0), (-2, 5, 2, 1)));
+	assert_eq!(world.get_block((-2, 5, 0, 0)), Some(1));
+	assert_eq!(world.get_block((-2, 5, 2, 1)), Some(6));
+}
\ No newline at end of file
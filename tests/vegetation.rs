@@ -0,0 +1,54 @@
+use fdm_toolkit::world::{Block, World};
+use fdm_toolkit::vegetation::{decorate, Plant};
+use fdm_toolkit::worldgen::TerrainGenerator;
+use fdm_toolkit::util::WorldRect4;
+use fdm_toolkit::chunk::Chunk;
+
+
+
+
+
+/// Tests to see if trees spread through all four dimensions, and across chunk borders, without replacing anything.
+#[test] fn grow_tree() {
+	let mut world = World::new();
+	for key in [(-1, -1, -1), (-1, -1, 0), (-1, 0, -1), (-1, 0, 0), (0, -1, -1), (0, -1, 0), (0, 0, -1), (0, 0, 0)] {
+		world.insert_chunk(key, Chunk::filled_with(Block::Air as u8));
+	}
+	world.fill(Block::Grass as u8, WorldRect4::new((-8, 0, -8, -8), (7, 9, 7, 7)));
+	world.set_block((0, 14, 1, 0), Block::Glass as u8);
+	
+	let placed = Plant::Tree.grow(&mut world, 3, (0, 10, 0, 0));
+	assert!(placed > 20);
+	assert_eq!(world.get_block((0, 10, 0, 0)), Some(Block::Wood as u8));
+	assert_eq!(world.get_block((0, 14, 1, 0)), Some(Block::Glass as u8));
+	
+	let top = (10..20).rev().find(|&y| world.get_block((0, y, 0, 0)) == Some(Block::Wood as u8)).unwrap();
+	for leaf in [(1, top, 0, 0), (-1, top, 0, 0), (0, top, 0, -1), (0, top, -1, 0), (0, top+1, 0, 0)] {
+		assert_eq!(world.get_block(leaf), Some(Block::Leaf as u8), "{leaf:?}");
+	}
+	
+	assert_eq!(Plant::Tree.grow(&mut world, 3, (0, 10, 0, 0)), 0);
+	assert_eq!(Plant::Tree.grow(&mut world, 3, (100, 10, 0, 0)), 0);
+	assert_eq!(world.chunks().count(), 8);
+}
+
+
+
+/// Tests to see if decorating generated terrain only grows plants which belong to its biomes.
+#[test] fn decorate_terrain() {
+	let generator = TerrainGenerator::new(11);
+	let mut world = World::new();
+	generator.generate_into(&mut world, (0..4).flat_map(|x| (0..4).map(move |w| (x, 0, w))));
+	
+	let area  = WorldRect4::new((0, 0, 0, 0), (31, 127, 7, 31));
+	let grown = decorate(&mut world, 5, area, 0.05);
+	assert!(grown > 0);
+	
+	for (x, y, z, w) in area.iter() {
+		let Some(id) = world.get_block((x, y, z, w)) else { continue; };
+		let below    = world.get_block((x, y-1, z, w)).unwrap_or(0);
+		if id == Block::TallGrass || id == Block::Bush { assert_eq!(below, Block::Grass as u8); }
+		if id == Block::MidnightBush { assert_eq!(below, Block::MidnightGrass as u8); }
+		if id == Block::SnowyBush { assert!(below == Block::Snow as u8 || below == Block::Ice as u8); }
+	}
+}
\ No newline at end of file
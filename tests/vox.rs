@@ -0,0 +1,61 @@
+use fdm_toolkit::vox::{VoxPalette, VoxModel};
+use fdm_toolkit::world::{Block, World};
+use fdm_toolkit::err::VoxError;
+
+
+
+
+
+/// Tests to see if a slice of a world survives being exported to, and imported from, a `.vox` model.
+#[test] fn vox_round_trip() {
+	let mut world = World::new();
+	world.set_block((-1, 0, 3, 5), Block::Stone as u8);
+	world.set_block(( 0, 1, 3, 5), Block::Glass as u8);
+	world.set_block(( 2, 1, 4, 5), Block::DeadlyOre as u8);
+	world.set_block(( 2, 1, 4, 6), Block::Sand as u8);
+	
+	let palette = VoxPalette::default();
+	let model   = VoxModel::from_world(&world, 5, (-1, 0, 3), (2, 1, 4), &palette).unwrap();
+	assert_eq!(model.size, (4, 2, 2));
+	assert_eq!(model.voxels.len(), 3);
+	
+	let read = VoxModel::from_bytes(&model.to_bytes()).unwrap();
+	assert_eq!(read, model);
+	
+	let mut copy = World::new();
+	assert_eq!(read.place_into(&mut copy, (9, 10, -4, -2), |color| palette.nearest_block(color)), 3);
+	assert_eq!(copy.get_block((9, 10, -4, -2)), Some(Block::Stone as u8));
+	assert_eq!(copy.get_block((10, 11, -4, -2)), Some(Block::Glass as u8));
+	assert_eq!(copy.get_block((12, 11, -3, -2)), Some(Block::DeadlyOre as u8));
+}
+
+
+
+/// Tests to see if regions which cannot fit in a `.vox` model are rejected.
+#[test] fn vox_too_large() {
+	assert!(VoxModel::from_world(&World::new(), 0, (0, 0, 0), (256, 0, 0), &VoxPalette::default()).is_err());
+	assert_eq!(
+		VoxModel::from_world(&World::new(), 0, (i64::MIN, 0, 0), (i64::MAX, 0, 0), &VoxPalette::default()),
+		Err(VoxError::TooLarge {size: (usize::MAX, 1, 1)})
+	);
+	assert!(VoxModel::from_bytes(b"NOPE").is_err());
+}
+
+
+
+/// Tests to see if models without a palette of their own use MagicaVoxel's default palette.
+#[test] fn vox_default_palette() {
+	let model = VoxModel {size: (1, 1, 1), voxels: vec![(0, 0, 0, 216)], palette: [[0; 4]; 256]};
+	let mut bytes = model.to_bytes();
+	bytes.truncate(bytes.len()-(12+1024));
+	let children = (bytes.len()-20) as u32;
+	bytes[16..20].copy_from_slice(&children.to_le_bytes());
+	
+	let read = VoxModel::from_bytes(&bytes).unwrap();
+	assert_eq!(read.palette, VoxModel::DEFAULT_PALETTE);
+	assert_eq!(read.palette[1],   [0xFF, 0xFF, 0xFF, 0xFF]);
+	assert_eq!(read.palette[2],   [0xFF, 0xFF, 0xCC, 0xFF]);
+	assert_eq!(read.palette[215], [0x00, 0x00, 0x33, 0xFF]);
+	assert_eq!(read.palette[216], [0xEE, 0x00, 0x00, 0xFF]);
+	assert_eq!(read.palette[255], [0x11, 0x11, 0x11, 0xFF]);
+}
\ No newline at end of file
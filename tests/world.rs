@@ -0,0 +1,37 @@
+use fdm_toolkit::util::{WorldRect4, FillParams, Rect4};
+use fdm_toolkit::world::{Block, World};
+
+
+
+
+
+/// Tests to see if a fill spanning several chunks touches (only) the blocks and chunks it should.
+#[test] fn fill_across_chunks() {
+	let mut world = World::new();
+	world.fill(Block::Stone as u8, WorldRect4::new((5, 3, -2, 7), (-3, 0, 9, 8)));
+	
+	assert_eq!(world.chunks().count(), 2*3*2);
+	assert_eq!(world.dirty_chunks().count(), 2*3*2);
+	assert!(world.is_dirty((-1, -1, 0)) && world.is_dirty((0, 1, 1)));
+	
+	assert_eq!(world.get_block((-3, 0, -2, 7)), Some(Block::Stone as u8));
+	assert_eq!(world.get_block((5, 3, 9, 8)), Some(Block::Stone as u8));
+	assert_eq!(world.get_block((5, 4, 9, 8)), Some(Block::Air as u8));
+	assert_eq!(world.get_block((6, 3, 9, 8)), Some(Block::Air as u8));
+	
+	world.clear_dirty();
+	assert_eq!(world.dirty_chunks().count(), 0);
+}
+
+
+
+/// Tests to see if a hollow fill keeps its shape when placed across chunk borders.
+#[test] fn hollow_fill_across_chunks() {
+	let mut world = World::new();
+	world.fill_with_params((-2, 126, -2, -2), FillParams::hollow(Block::Glass as u8, Rect4::new((0, 0, 0, 0), (3, 3, 3, 3))));
+	
+	assert_eq!(world.get_block((-2, 126, -2, -2)), Some(Block::Glass as u8));
+	assert_eq!(world.get_block((1, 127, 1, 1)), Some(Block::Glass as u8));
+	assert_eq!(world.get_block((-1, 127, -1, 0)), Some(Block::Air as u8));
+	assert_eq!(world.get_block((-1, 128, -1, 0)), None);
+}
\ No newline at end of file
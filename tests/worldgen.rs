@@ -0,0 +1,49 @@
+use fdm_toolkit::world::{Block, World};
+use fdm_toolkit::worldgen::TerrainGenerator;
+use fdm_toolkit::chunk::ChunkData;
+use fdm_toolkit::biome::Biome;
+
+
+
+
+
+/// Tests to see if generated chunks only depend on the seed and their position.
+#[test] fn deterministic_generation() {
+	let generator = TerrainGenerator::new(42);
+	
+	let mut world = World::new();
+	generator.generate_into(&mut world, [(0, 0, 0), (-1, 2, 3)]);
+	assert_eq!(world.chunk((-1, 2, 3)), Some(&generator.generate_chunk((-1, 2, 3))));
+	assert_eq!(world.chunk((0, 0, 0)), Some(&TerrainGenerator::new(42).generate_chunk((0, 0, 0))));
+	assert_ne!(world.chunk((0, 0, 0)), Some(&TerrainGenerator::new(43).generate_chunk((0, 0, 0))));
+	assert_eq!(world.dirty_chunks().count(), 0);
+}
+
+
+
+/// Tests to see if generated columns are layered as their biome says they should be.
+#[test] fn terrain_layers() {
+	let generator = TerrainGenerator::new(7);
+	let chunk     = generator.generate_chunk((3, -2, 5));
+	
+	for x in 0..8 { for z in 0..8 { for w in 0..8 {
+		let at     = (24 + x as i64, -16 + z as i64, 40 + w as i64);
+		let biome  = generator.biome_at(at);
+		let height = generator.height_at(at);
+		
+		assert_eq!(chunk.get_block((x, 0, z, w)), Some(Block::Lava as u8));
+		assert_eq!(chunk.get_block((x, height, z, w)), Some(biome.surface() as u8));
+		assert_eq!(chunk.get_block((x, height-1, z, w)), Some(biome.subsurface() as u8));
+		
+		let deep = chunk.get_block((x, 30, z, w)).unwrap();
+		assert!([biome.stone() as u8, Block::IronOre as u8].contains(&deep));
+		assert_eq!(chunk.get_block((x, 127, z, w)), Some(Block::Air as u8));
+	} } }
+	
+	let mut biomes = Biome::ALL.map(|biome| (biome, 0));
+	for x in -40..40 { for w in -40..40 {
+		let biome = generator.biome_at((x*16, 0, w*16));
+		biomes.iter_mut().find(|(b, _)| *b == biome).unwrap().1 += 1;
+	} }
+	assert!(biomes.iter().all(|&(_, n)| n > 0), "{biomes:?}");
+}
\ No newline at end of file